@@ -0,0 +1,209 @@
+#!/usr/bin/env python3
+"""Generate the Unicode tables in `src/unicode/`.
+
+Most of the data is taken from the `regex` module, which must be installed:
+
+    pip install regex
+    python3 scripts/unicode_tables.py
+
+Case folding is taken from `CaseFolding.txt` in the Unicode Character Database, which is
+downloaded unless the path of a local copy is given:
+
+    python3 scripts/unicode_tables.py path/to/CaseFolding.txt
+
+Every name is stored in its loosely matched form: lowercase, without any spaces,
+underscores or hyphens.
+"""
+
+import pathlib
+import sys
+import urllib.request
+
+import regex
+import regex._regex as _regex
+import regex._regex_core as core
+
+UNICODE_VERSION = "17.0.0"
+
+OUT = pathlib.Path(__file__).resolve().parent.parent / "src" / "unicode"
+
+# Binary properties that are not defined by the UCD, or that only exist to derive others.
+EXCLUDED_PROPERTIES = {
+    "ALPHANUMERIC",
+    "BLANK",
+    "GRAPH",
+    "HORIZSPACE",
+    "PRINT",
+    "VERTSPACE",
+    "WORD",
+    "XDIGIT",
+}
+
+HAYSTACK = "".join(map(chr, range(0x110000)))
+
+
+def ranges(pattern):
+    """Get the ranges of scalar values matched by `pattern`."""
+    out = []
+
+    for m in regex.finditer(f"(?:{pattern})+", HAYSTACK):
+        start, end = m.start(), m.end() - 1
+
+        # Surrogates are not scalar values.
+        if start < 0xD800 <= end:
+            out.append((start, 0xD7FF))
+        if end > 0xDFFF >= start:
+            out.append((0xE000, end))
+        if end < 0xD800 or start > 0xDFFF:
+            out.append((start, end))
+
+    return sorted(out)
+
+
+def values(name):
+    """Get the values of the property `name`, along with their aliases."""
+    prop_id, by_name = core.PROPERTIES[name]
+    canonical = core.PROPERTY_NAMES[prop_id][1]
+
+    out = {}
+    for alias, value in by_name.items():
+        if "&" in alias:
+            continue
+        out.setdefault(canonical[value].lower(), set()).add(alias.lower())
+
+    return out
+
+
+def aliases_of(prop_id):
+    return sorted(k.lower() for k, (i, _) in core.PROPERTIES.items() if i == prop_id)
+
+
+def char(c):
+    return f"'\\u{{{c:x}}}'"
+
+
+def table(name, doc, entries):
+    lines = [f"/// {doc}", f"pub(super) const {name}: &[(&str, &[(char, char)])] = &["]
+
+    for key, rs in entries:
+        lines.append(f'    ("{key}", &[')
+        items = [f"({char(a)}, {char(b)})" for a, b in rs]
+        for i in range(0, len(items), 3):
+            lines.append("        " + ", ".join(items[i : i + 3]) + ",")
+        lines.append("    ]),")
+
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def alias_table(doc, aliases):
+    lines = [f"/// {doc}", "pub(super) const ALIASES: &[(&str, &str)] = &["]
+    lines += [f'    ("{alias}", "{key}"),' for alias, key in sorted(aliases)]
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def write(path, *sections):
+    header = (
+        f"// DO NOT EDIT: generated by `scripts/unicode_tables.py` from Unicode {UNICODE_VERSION}.\n"
+    )
+    (OUT / path).write_text(header + "\n" + "\n\n".join(sections) + "\n")
+
+
+def case_folding(path):
+    """Get every scalar value that is equivalent to others under simple case folding,
+    along with them.
+
+    Only the common and simple mappings of `CaseFolding.txt` are used, so the Turkic
+    mappings of I and i are not. Each value is listed with every other value that folds to
+    the same one, so that the equivalence is symmetric.
+    """
+    if path is None:
+        url = f"https://www.unicode.org/Public/{UNICODE_VERSION}/ucd/CaseFolding.txt"
+        with urllib.request.urlopen(url) as response:
+            text = response.read().decode()
+    else:
+        text = pathlib.Path(path).read_text()
+
+    classes = {}
+
+    for line in text.splitlines():
+        fields = [field.strip() for field in line.split("#", 1)[0].split(";")]
+        if len(fields) < 3 or fields[1] not in ("C", "S"):
+            continue
+
+        c, folded = int(fields[0], 16), int(fields[2], 16)
+        classes.setdefault(folded, {folded}).add(c)
+
+    return sorted(
+        (c, sorted(members - {c})) for members in classes.values() for c in members
+    )
+
+
+def case_folding_table(doc, entries):
+    lines = [f"/// {doc}", "pub(super) const SIMPLE: &[(char, &[char])] = &["]
+    lines += [
+        f"    ({char(c)}, &[{', '.join(map(char, others))}]),"
+        for c, others in entries
+    ]
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def by_value(prop, query):
+    entries, aliases = [], []
+
+    for key, names in sorted(values(prop).items()):
+        rs = ranges(query.format(key))
+        if not rs and key != "surrogate":
+            continue
+
+        entries.append((key, rs))
+        aliases += [(alias, key) for alias in names if alias != key]
+
+    return entries, aliases
+
+
+def main(case_folding_path=None):
+    entries, aliases = by_value("GENERALCATEGORY", r"\p{{gc={}}}")
+    write(
+        "general_category.rs",
+        table("BY_NAME", "Every general category and group of them, by name.", entries),
+        alias_table("Other names of general categories.", aliases),
+    )
+
+    entries, aliases = by_value("SCRIPT", r"\p{{sc={}}}")
+    write(
+        "script.rs",
+        table("BY_NAME", "Every script, by name.", entries),
+        alias_table("Other names of scripts.", aliases),
+    )
+
+    entries, aliases = [("ascii", [(0, 0x7F)])], []
+    for prop_id, (name, vals) in sorted(core.PROPERTY_NAMES.items()):
+        if set(vals.values()) != {"FALSE", "TRUE"}:
+            continue
+        if name in EXCLUDED_PROPERTIES or name.startswith(("OTHER", "POSIX")):
+            continue
+
+        key = name.lower()
+        entries.append((key, ranges(rf"\p{{{name}}}")))
+        aliases += [(alias, key) for alias in aliases_of(prop_id) if alias != key]
+
+    write(
+        "property.rs",
+        table("BY_NAME", "Every binary property, by name.", sorted(entries)),
+        alias_table("Other names of binary properties.", aliases),
+    )
+
+    write(
+        "case_folding.rs",
+        case_folding_table(
+            "Each scalar value equivalent to others under simple case folding, with them.",
+            case_folding(case_folding_path),
+        ),
+    )
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:2])
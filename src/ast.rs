@@ -1,16 +1,30 @@
 mod alt;
+mod backref;
+mod char_class;
 mod class;
 mod concat;
+mod flags;
 mod group;
 mod literal;
+mod look;
+mod lookaround;
 mod repeat;
+mod span;
 
 pub use alt::*;
+pub use backref::*;
+pub use char_class::*;
 pub use class::*;
 pub use concat::*;
+pub use flags::*;
 pub use group::*;
 pub use literal::*;
+pub use look::*;
+pub use lookaround::*;
 pub use repeat::*;
+pub use span::*;
+
+use std::ptr;
 
 use bumpalo::Bump;
 
@@ -22,29 +36,112 @@ pub type Children<'a> = NonEmpty<Ast<'a>>;
 pub enum Ast<'a> {
     #[default]
     Empty,
+    /// Matches nothing, not even the empty string.
+    Fail,
+    /// Matches any single byte.
     Dot,
+    /// An inline change to the active flags, which matches the empty string.
+    Flags(SetFlags),
+    /// An assertion about the current position, which matches the empty string.
+    Look(Look),
+    Backref(Backref<'a>),
     Lit(Lit<'a>),
     Class(Class<'a>),
+    /// Matches the UTF-8 encoding of any scalar value in a set.
+    CharClass(CharClass<'a>),
     Alt(Alt<'a>),
     Concat(Concat<'a>),
     Group(Group<'a>),
     Repeat(Repeat<'a>),
+    Lookaround(Lookaround<'a>),
 }
 
 impl<'a> Ast<'a> {
-    #[inline]
+    /// Copy this tree into `bump`.
+    ///
+    /// The tree is walked with an explicit stack, so that copying a deeply nested tree
+    /// cannot overflow the call stack.
     #[must_use]
     pub fn clone_into<'b>(&self, bump: &'b Bump) -> Ast<'b> {
-        match self {
-            Ast::Empty => Ast::Empty,
-            Ast::Dot => Ast::Dot,
-            Ast::Lit(l) => Ast::Lit(l.clone_into(bump)),
-            Ast::Class(c) => Ast::Class(c.clone_into(bump)),
-            Ast::Alt(a) => Ast::Alt(a.clone_into(bump)),
-            Ast::Concat(c) => Ast::Concat(c.clone_into(bump)),
-            Ast::Group(g) => Ast::Group(g.clone_into(bump)),
-            Ast::Repeat(r) => Ast::Repeat(r.clone_into(bump)),
+        // Each node is visited twice: once to schedule its children, and once to build
+        // its copy from theirs, which are then at the end of `done`.
+        let mut stack = vec![(self, false)];
+        let mut done = Vec::new();
+
+        while let Some((node, built)) = stack.pop() {
+            let copy = match (node, node.children()) {
+                (Ast::Empty, _) => Ast::Empty,
+                (Ast::Fail, _) => Ast::Fail,
+                (Ast::Dot, _) => Ast::Dot,
+                (Ast::Flags(f), _) => Ast::Flags(*f),
+                (Ast::Look(l), _) => Ast::Look(*l),
+                (Ast::Backref(b), _) => Ast::Backref(b.clone_into(bump)),
+                (Ast::Lit(l), _) => Ast::Lit(l.clone_into(bump)),
+                (Ast::Class(c), _) => Ast::Class(c.clone_into(bump)),
+                (Ast::CharClass(c), _) => Ast::CharClass(c.clone_into(bump)),
+                (_, Some(children)) if !built => {
+                    stack.push((node, true));
+                    stack.extend(children.iter().rev().map(|child| (child, false)));
+                    continue;
+                }
+                (_, children) => {
+                    let len = children.map_or(0, |children| children.len().get());
+                    let mut children = done.drain(done.len() - len..);
+
+                    match node {
+                        Ast::Alt(_) => Ast::Alt(Alt {
+                            children: NonEmpty::new_mut(bump.alloc_slice_fill_iter(children)),
+                        }),
+                        Ast::Concat(_) => Ast::Concat(Concat {
+                            children: NonEmpty::new_mut(bump.alloc_slice_fill_iter(children)),
+                        }),
+                        Ast::Group(g) => Ast::Group(Group {
+                            flags: g.flags,
+                            capture: g.capture.map(|capture| capture.clone_into(bump)),
+                            atomic: g.atomic,
+                            child: bump.alloc(children.next().unwrap()),
+                        }),
+                        Ast::Repeat(r) => Ast::Repeat(Repeat {
+                            kind: r.kind,
+                            greediness: r.greediness,
+                            child: bump.alloc(children.next().unwrap()),
+                        }),
+                        Ast::Lookaround(l) => Ast::Lookaround(Lookaround {
+                            kind: l.kind,
+                            child: bump.alloc(children.next().unwrap()),
+                        }),
+                        _ => unreachable!("node has children"),
+                    }
+                }
+            };
+
+            done.push(copy);
+        }
+
+        done.pop().unwrap()
+    }
+
+    /// Get every capture group in this tree, in the order of their opening parentheses.
+    #[must_use]
+    pub fn captures(&self) -> Vec<Capture<'a>> {
+        let mut captures = Vec::new();
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            if let Ast::Group(Group {
+                capture: Some(capture),
+                ..
+            }) = node
+            {
+                captures.push(*capture);
+            }
+
+            if let Some(children) = node.children() {
+                stack.extend(children.iter().rev());
+            }
         }
+
+        captures
     }
 
     #[inline]
@@ -52,13 +149,19 @@ impl<'a> Ast<'a> {
     pub fn children(&self) -> Option<&Children<'a>> {
         match self {
             Ast::Empty => None,
+            Ast::Fail => None,
             Ast::Dot => None,
+            Ast::Flags(_) => None,
+            Ast::Look(_) => None,
+            Ast::Backref(_) => None,
             Ast::Lit(_) => None,
             Ast::Class(_) => None,
+            Ast::CharClass(_) => None,
             Ast::Alt(a) => Some(a.children),
             Ast::Concat(c) => Some(c.children),
             Ast::Group(g) => Some(Children::from_ref(g.child)),
             Ast::Repeat(r) => Some(Children::from_ref(r.child)),
+            Ast::Lookaround(l) => Some(Children::from_ref(l.child)),
         }
     }
 
@@ -67,47 +170,122 @@ impl<'a> Ast<'a> {
     pub fn children_mut(&mut self) -> Option<&mut Children<'a>> {
         match self {
             Ast::Empty => None,
+            Ast::Fail => None,
             Ast::Dot => None,
+            Ast::Flags(_) => None,
+            Ast::Look(_) => None,
+            Ast::Backref(_) => None,
             Ast::Lit(_) => None,
             Ast::Class(_) => None,
+            Ast::CharClass(_) => None,
             Ast::Alt(a) => Some(a.children),
             Ast::Concat(c) => Some(c.children),
             Ast::Group(g) => Some(Children::from_mut(g.child)),
             Ast::Repeat(r) => Some(Children::from_mut(r.child)),
+            Ast::Lookaround(l) => Some(Children::from_mut(l.child)),
         }
     }
 
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        match self {
-            Self::Empty => true,
-            _ => false,
-        }
+        matches!(self, Self::Empty)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Self::Fail)
     }
 
     #[inline]
     #[must_use]
     pub fn is_dot(&self) -> bool {
-        match self {
-            Self::Dot => true,
-            _ => false,
-        }
+        matches!(self, Self::Dot)
     }
 
     pub fn normalize(&mut self) {
+        self.normalize_spanned(&mut SpanMap::new());
+    }
+
+    /// Normalize this node, keeping `spans` up to date.
+    ///
+    /// A node that is collapsed into one of its children keeps its own span. The tree is
+    /// walked with an explicit stack, so that normalizing a deeply nested tree cannot
+    /// overflow the call stack.
+    pub fn normalize_spanned(&mut self, spans: &mut SpanMap) {
+        let mut nodes = Vec::new();
+        let mut stack = vec![ptr::from_mut(self)];
+
+        while let Some(node) = stack.pop() {
+            nodes.push(node);
+
+            // SAFETY: Every pointer on the stack refers to a distinct node of this tree, and
+            // none of them have been modified yet.
+            if let Some(children) = unsafe { &mut *node }.children_mut() {
+                stack.extend(children.iter_mut().map(ptr::from_mut));
+            }
+        }
+
+        // Every node comes after its ancestors in `nodes`, so this normalizes each node
+        // after all of its descendants.
+        for node in nodes.into_iter().rev() {
+            // SAFETY: Normalizing a node only modifies its own subtree, which contains none
+            // of the nodes that are left, so `node` still refers to a node of this tree.
+            unsafe { &mut *node }.normalize_node(spans);
+        }
+    }
+
+    /// Normalize this node, whose children have already been normalized.
+    fn normalize_node(&mut self, spans: &mut SpanMap) {
         match self {
             // Ast::Lit(_) => todo!(),
-            Ast::Class(_) => Class::normalize(self),
-            Ast::Alt(_) => Alt::normalize(self),
-            Ast::Concat(_) => Concat::normalize(self),
-            Ast::Group(_) => Group::normalize(self),
-            Ast::Repeat(_) => Repeat::normalize(self),
+            // Flags were already applied by the parser.
+            Ast::Flags(_) => *self = Ast::Empty,
+            Ast::Class(_) => Class::normalize(self, spans),
+            Ast::CharClass(_) => CharClass::normalize(self, spans),
+            Ast::Alt(_) => Alt::normalize(self, spans),
+            Ast::Concat(_) => Concat::normalize(self, spans),
+            Ast::Group(_) => Group::normalize(self, spans),
+            Ast::Repeat(_) => Repeat::normalize(self, spans),
+            Ast::Lookaround(_) => Lookaround::normalize(self, spans),
             _ => {}
         }
     }
 }
 
+/// Move every child for which `keep` returns true to the front of `children`, keeping
+/// their order and spans, and return how many there are.
+///
+/// The spans of every other child are removed.
+fn retain<'a>(
+    children: &mut Children<'a>,
+    spans: &mut SpanMap,
+    mut keep: impl FnMut(&Ast<'a>) -> bool,
+) -> usize {
+    let mut len = 0;
+
+    for i in 0..children.len().get() {
+        if !keep(&children[i]) {
+            spans.remove_tree(&children[i]);
+            continue;
+        }
+
+        if i != len {
+            let span = spans.remove(&children[i]);
+            children.swap(i, len);
+
+            if let Some(span) = span {
+                spans.insert(&children[len], span);
+            }
+        }
+
+        len += 1;
+    }
+
+    len
+}
+
 macro_rules! methods {
     ($(
         $kind:ident(
@@ -122,10 +300,7 @@ macro_rules! methods {
             #[inline]
             #[must_use]
             pub fn $is_kind(&self) -> bool {
-                match self {
-                    Self::$kind(..) => true,
-                    _ => false,
-                }
+                matches!(self, Self::$kind(..))
             }
 
             #[inline]
@@ -176,6 +351,12 @@ impl<'a> Ast<'a> {
             as_class_mut,
             take_class,
         ),
+        CharClass(
+            is_char_class,
+            as_char_class,
+            as_char_class_mut,
+            take_char_class,
+        ),
         Alt(
             is_alt,
             as_alt,
@@ -200,5 +381,11 @@ impl<'a> Ast<'a> {
             as_repeat_mut,
             take_repeat,
         ),
+        Lookaround(
+            is_lookaround,
+            as_lookaround,
+            as_lookaround_mut,
+            take_lookaround,
+        ),
     }
 }
@@ -1,6 +1,7 @@
 use std::mem;
 
-use super::{Ast, Children};
+use super::{retain, Ast, Children, SpanMap};
+use crate::util::slice::NonEmpty;
 use bumpalo::Bump;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21,14 +22,34 @@ impl<'a> Alt<'a> {
         }
     }
 
+    /// Normalize `this`, whose children must already be normalized.
     #[inline]
-    pub fn normalize(this: &mut Ast<'a>) {
-        if let Ast::Alt(Alt { children }) = this {
-            children.iter_mut().for_each(Ast::normalize);
+    pub fn normalize(this: &mut Ast<'a>, spans: &mut SpanMap) {
+        let Ast::Alt(Alt { children }) = this else {
+            return;
+        };
 
-            if children.len().get() == 1 {
+        // Alternatives that match nothing can never be taken, and none of them contain
+        // capture groups, which are never folded away.
+        let len = retain(children, spans, |child| !child.is_fail());
+
+        match len {
+            0 => *this = Ast::Fail,
+            1 => {
+                spans.remove(children.first());
                 *this = mem::take(children.first_mut());
             }
+            len if len < children.len().get() => {
+                let Some(Alt { children }) = this.take_alt() else {
+                    unreachable!()
+                };
+                let children: &'a mut [Ast<'a>] = children.into();
+
+                *this = Ast::Alt(Alt {
+                    children: NonEmpty::new_mut(&mut children[..len]),
+                });
+            }
+            _ => {}
         }
     }
 }
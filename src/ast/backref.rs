@@ -0,0 +1,41 @@
+use bumpalo::Bump;
+
+/// A backreference, which matches the same text that a capture group matched, as in `\1`
+/// or `\k<name>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Backref<'a> {
+    /// The index of the referenced capture group, which comes before the backreference.
+    pub index: u32,
+    /// The name that the group is referred to by, or `None` if it is referred to by its
+    /// index.
+    pub name: Option<&'a str>,
+    /// How the text is compared with that matched by the group.
+    pub case: BackrefCase,
+}
+
+impl Backref<'_> {
+    #[inline]
+    #[must_use]
+    pub fn clone_into<'b>(&self, bump: &'b Bump) -> Backref<'b> {
+        Backref {
+            index: self.index,
+            name: self.name.map(|name| &*bump.alloc_str(name)),
+            case: self.case,
+        }
+    }
+}
+
+/// How a backreference compares text with the text matched by its group, which follows the
+/// flags that were active for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum BackrefCase {
+    /// The bytes must be the same.
+    #[default]
+    Sensitive,
+    /// ASCII letters match both their uppercase and lowercase forms, as in case-insensitive
+    /// mode.
+    Ascii,
+    /// Scalar values match those they are equivalent to under simple case folding, as in
+    /// case-insensitive Unicode mode.
+    Simple,
+}
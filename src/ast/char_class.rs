@@ -0,0 +1,206 @@
+use crate::{unicode, util::slice::NonEmpty};
+use bumpalo::Bump;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut, RangeInclusive},
+};
+
+use super::{
+    canonicalize_ranges, class::merge_sorted, complement_ranges, difference_ranges,
+    intersect_ranges, symmetric_difference_ranges, Ast, ByteRange, ClassRange, SpanMap,
+};
+
+/// A class of scalar values, which matches their UTF-8 encodings.
+///
+/// Unlike a [`Class`](super::Class), this is not lowered to byte classes by the parser, so
+/// that it keeps the shape of the pattern it was written as.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct CharClass<'a> {
+    pub ranges: &'a mut NonEmpty<CharRange>,
+}
+
+impl<'a> CharClass<'a> {
+    #[inline]
+    #[must_use]
+    pub fn clone_into<'b>(&self, bump: &'b Bump) -> CharClass<'b> {
+        let slice = bump.alloc_slice_copy(self);
+
+        CharClass {
+            ranges: slice.try_into().unwrap(),
+        }
+    }
+
+    /// Get the class matching every scalar value that this one does not, or `None` if this
+    /// class matches every scalar value.
+    #[must_use]
+    pub fn complement<'b>(&self, bump: &'b Bump) -> Option<CharClass<'b>> {
+        CharClass::alloc(bump, &complement_ranges(self))
+    }
+
+    /// Get the class matching every scalar value matched by either this class or `other`.
+    #[must_use]
+    pub fn union<'b>(&self, other: &CharClass<'_>, bump: &'b Bump) -> CharClass<'b> {
+        let mut ranges = [self.as_slice(), other.as_slice()].concat();
+        canonicalize_ranges(&mut ranges);
+
+        CharClass::alloc(bump, &ranges).expect("a union of classes is not empty")
+    }
+
+    /// Get the class matching every scalar value matched by both this class and `other`, or
+    /// `None` if there are none.
+    #[must_use]
+    pub fn intersection<'b>(&self, other: &CharClass<'_>, bump: &'b Bump) -> Option<CharClass<'b>> {
+        CharClass::alloc(bump, &intersect_ranges(self, other))
+    }
+
+    /// Get the class matching every scalar value matched by this class but not `other`, or
+    /// `None` if there are none.
+    #[must_use]
+    pub fn difference<'b>(&self, other: &CharClass<'_>, bump: &'b Bump) -> Option<CharClass<'b>> {
+        CharClass::alloc(bump, &difference_ranges(self, other))
+    }
+
+    /// Get the class matching every scalar value matched by exactly one of this class and
+    /// `other`, or `None` if there are none.
+    #[must_use]
+    pub fn symmetric_difference<'b>(
+        &self,
+        other: &CharClass<'_>,
+        bump: &'b Bump,
+    ) -> Option<CharClass<'b>> {
+        CharClass::alloc(bump, &symmetric_difference_ranges(self, other))
+    }
+
+    /// Check whether this class matches `c`.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, c: char) -> bool {
+        self.iter().any(|range| range.to_inclusive().contains(&c))
+    }
+
+    #[inline]
+    pub fn normalize(this: &mut Ast<'a>, _spans: &mut SpanMap) {
+        let Some(CharClass { ranges }) = this.take_char_class() else {
+            return;
+        };
+        let ranges: &'a mut [CharRange] = ranges.into();
+
+        let len = merge_sorted(ranges);
+
+        *this = Ast::CharClass(CharClass {
+            ranges: NonEmpty::new_mut(&mut ranges[..len]),
+        });
+    }
+
+    #[inline]
+    fn alloc<'b>(bump: &'b Bump, ranges: &[CharRange]) -> Option<CharClass<'b>> {
+        NonEmpty::try_new_mut(bump.alloc_slice_copy(ranges))
+            .ok()
+            .map(|ranges| CharClass { ranges })
+    }
+}
+
+impl Deref for CharClass<'_> {
+    type Target = NonEmpty<CharRange>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.ranges
+    }
+}
+
+impl DerefMut for CharClass<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ranges
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CharRange {
+    pub start: char,
+    pub end: char,
+}
+
+impl CharRange {
+    #[inline]
+    #[must_use]
+    pub const fn new(start: char, end: char) -> CharRange {
+        CharRange { start, end }
+    }
+
+    /// Get the number of scalar values in this range.
+    #[inline]
+    #[must_use]
+    pub fn count(self) -> u32 {
+        let len = u32::from(self.end) - u32::from(self.start) + 1;
+
+        if self.start <= '\u{D7FF}' && self.end >= '\u{E000}' {
+            len - 0x800
+        } else {
+            len
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_inclusive(self) -> RangeInclusive<char> {
+        self.start..=self.end
+    }
+}
+
+impl ClassRange for CharRange {
+    type Value = char;
+
+    const MIN: char = '\0';
+    const MAX: char = char::MAX;
+
+    #[inline]
+    fn new(start: char, end: char) -> CharRange {
+        CharRange::new(start, end)
+    }
+
+    #[inline]
+    fn start(self) -> char {
+        self.start
+    }
+
+    #[inline]
+    fn end(self) -> char {
+        self.end
+    }
+
+    /// Get the scalar value after `value`, so that ranges on either side of the surrogates
+    /// are adjacent.
+    #[inline]
+    fn successor(value: char) -> Option<char> {
+        unicode::successor(value)
+    }
+
+    #[inline]
+    fn predecessor(value: char) -> Option<char> {
+        unicode::predecessor(value)
+    }
+}
+
+impl fmt::Debug for CharRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+impl From<(char, char)> for CharRange {
+    #[inline]
+    fn from((start, end): (char, char)) -> CharRange {
+        CharRange::new(start, end)
+    }
+}
+
+/// Take the bytes of a range as the scalar values of the same number.
+impl From<ByteRange> for CharRange {
+    #[inline]
+    fn from(range: ByteRange) -> CharRange {
+        CharRange::new(char::from(range.start), char::from(range.end))
+    }
+}
@@ -6,7 +6,7 @@ use std::{
     ops::{Deref, DerefMut, Range, RangeInclusive},
 };
 
-use super::Ast;
+use super::{Ast, SpanMap};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -25,13 +25,70 @@ impl<'a> Class<'a> {
         }
     }
 
+    /// Get the class matching every byte that this one does not, or `None` if this class
+    /// matches every byte.
+    #[must_use]
+    pub fn complement<'b>(&self, bump: &'b Bump) -> Option<Class<'b>> {
+        Class::alloc(bump, &complement_ranges(self))
+    }
+
+    /// Get the class matching every byte matched by either this class or `other`.
+    #[must_use]
+    pub fn union<'b>(&self, other: &Class<'_>, bump: &'b Bump) -> Class<'b> {
+        let mut ranges = [self.as_slice(), other.as_slice()].concat();
+        canonicalize_ranges(&mut ranges);
+
+        Class::alloc(bump, &ranges).expect("a union of classes is not empty")
+    }
+
+    /// Get the class matching every byte matched by both this class and `other`, or `None`
+    /// if there are none.
+    #[must_use]
+    pub fn intersection<'b>(&self, other: &Class<'_>, bump: &'b Bump) -> Option<Class<'b>> {
+        Class::alloc(bump, &intersect_ranges(self, other))
+    }
+
+    /// Get the class matching every byte matched by this class but not `other`, or `None`
+    /// if there are none.
+    #[must_use]
+    pub fn difference<'b>(&self, other: &Class<'_>, bump: &'b Bump) -> Option<Class<'b>> {
+        Class::alloc(bump, &difference_ranges(self, other))
+    }
+
+    /// Get the class matching every byte matched by exactly one of this class and `other`,
+    /// or `None` if there are none.
+    #[must_use]
+    pub fn symmetric_difference<'b>(&self, other: &Class<'_>, bump: &'b Bump) -> Option<Class<'b>> {
+        Class::alloc(bump, &symmetric_difference_ranges(self, other))
+    }
+
+    /// Check whether this class matches `byte`.
     #[inline]
-    pub fn normalize(this: &mut Ast<'a>) {
-        if let Ast::Class(Class { ranges }) = this {
-            // NOTE: preserving initial order is not necessary
-            ranges.sort_unstable_by_key(|range| range.start);
-            // TODO: Merge overlapping ranges.
-        }
+    #[must_use]
+    pub fn contains(&self, byte: u8) -> bool {
+        self.iter()
+            .any(|range| range.to_inclusive().contains(&byte))
+    }
+
+    #[inline]
+    pub fn normalize(this: &mut Ast<'a>, _spans: &mut SpanMap) {
+        let Some(Class { ranges }) = this.take_class() else {
+            return;
+        };
+        let ranges: &'a mut [ByteRange] = ranges.into();
+
+        let len = merge_sorted(ranges);
+
+        *this = Ast::Class(Class {
+            ranges: NonEmpty::new_mut(&mut ranges[..len]),
+        });
+    }
+
+    #[inline]
+    fn alloc<'b>(bump: &'b Bump, ranges: &[ByteRange]) -> Option<Class<'b>> {
+        NonEmpty::try_new_mut(bump.alloc_slice_copy(ranges))
+            .ok()
+            .map(|ranges| Class { ranges })
     }
 }
 
@@ -51,6 +108,131 @@ impl DerefMut for Class<'_> {
     }
 }
 
+/// A range of the values in a class, over which the set operations on classes are
+/// implemented.
+pub trait ClassRange: Copy + Ord {
+    type Value: Copy + Ord;
+
+    /// The least value a range can contain.
+    const MIN: Self::Value;
+    /// The greatest value a range can contain.
+    const MAX: Self::Value;
+
+    #[must_use]
+    fn new(start: Self::Value, end: Self::Value) -> Self;
+
+    #[must_use]
+    fn start(self) -> Self::Value;
+
+    #[must_use]
+    fn end(self) -> Self::Value;
+
+    /// Get the value after `value`, if there is one.
+    #[must_use]
+    fn successor(value: Self::Value) -> Option<Self::Value>;
+
+    /// Get the value before `value`, if there is one.
+    #[must_use]
+    fn predecessor(value: Self::Value) -> Option<Self::Value>;
+
+    /// Merge this range with `other`, if they overlap or are adjacent.
+    #[must_use]
+    fn merge(self, other: Self) -> Option<Self> {
+        let (first, second) = if self <= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        // The ranges overlap or are adjacent if the second starts no later than the value
+        // after the end of the first.
+        if Self::successor(first.end()).is_some_and(|next| second.start() > next) {
+            return None;
+        }
+
+        Some(Self::new(first.start(), first.end().max(second.end())))
+    }
+}
+
+/// Sort `ranges` and merge any that overlap or are adjacent.
+pub fn canonicalize_ranges<R: ClassRange>(ranges: &mut Vec<R>) {
+    let len = merge_sorted(ranges);
+    ranges.truncate(len);
+}
+
+/// Get the canonical ranges of every value not in any of `ranges`.
+///
+/// `ranges` need not be sorted, and may overlap.
+#[must_use]
+pub fn complement_ranges<R: ClassRange>(ranges: &[R]) -> Vec<R> {
+    let mut sorted = ranges.to_vec();
+    canonicalize_ranges(&mut sorted);
+
+    let mut out = Vec::with_capacity(sorted.len() + 1);
+    let mut next = Some(R::MIN);
+
+    for range in sorted {
+        if let Some(start) = next.filter(|&start| start < range.start()) {
+            let end = R::predecessor(range.start()).expect("start is greater than next");
+            out.push(R::new(start, end));
+        }
+
+        next = R::successor(range.end());
+    }
+
+    if let Some(start) = next {
+        out.push(R::new(start, R::MAX));
+    }
+
+    out
+}
+
+/// Get the canonical ranges of every value in both `a` and `b`.
+#[must_use]
+pub fn intersect_ranges<R: ClassRange>(a: &[R], b: &[R]) -> Vec<R> {
+    complement_ranges(&[complement_ranges(a), complement_ranges(b)].concat())
+}
+
+/// Get the canonical ranges of every value in `a` but not `b`.
+#[must_use]
+pub fn difference_ranges<R: ClassRange>(a: &[R], b: &[R]) -> Vec<R> {
+    complement_ranges(&[&complement_ranges(a), b].concat())
+}
+
+/// Get the canonical ranges of every value in exactly one of `a` and `b`.
+#[must_use]
+pub fn symmetric_difference_ranges<R: ClassRange>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut ranges = difference_ranges(a, b);
+    ranges.extend(difference_ranges(b, a));
+    canonicalize_ranges(&mut ranges);
+
+    ranges
+}
+
+/// Sort `ranges` and merge any that overlap or are adjacent into the front of it, returning
+/// how many remain.
+pub(super) fn merge_sorted<R: ClassRange>(ranges: &mut [R]) -> usize {
+    // NOTE: preserving initial order is not necessary
+    ranges.sort_unstable();
+
+    let mut len = 0usize;
+
+    for i in 0..ranges.len() {
+        match len
+            .checked_sub(1)
+            .and_then(|last| ranges[last].merge(ranges[i]))
+        {
+            Some(merged) => ranges[len - 1] = merged,
+            None => {
+                ranges[len] = ranges[i];
+                len += 1;
+            }
+        }
+    }
+
+    len
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct ByteRange {
     pub start: u8,
@@ -60,8 +242,34 @@ pub struct ByteRange {
 impl ByteRange {
     #[inline]
     #[must_use]
-    pub const fn merge(self, _other: ByteRange) -> Option<ByteRange> {
-        todo!()
+    pub const fn new(start: u8, end: u8) -> ByteRange {
+        ByteRange { start, end }
+    }
+
+    /// Merge this range with `other`, if they overlap or are adjacent.
+    #[inline]
+    #[must_use]
+    pub const fn merge(self, other: ByteRange) -> Option<ByteRange> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        // The ranges overlap or are adjacent if the second starts no later than the byte
+        // after the end of the first.
+        if second.start as u16 > first.end as u16 + 1 {
+            return None;
+        }
+
+        Some(ByteRange {
+            start: first.start,
+            end: if first.end > second.end {
+                first.end
+            } else {
+                second.end
+            },
+        })
     }
 
     #[inline]
@@ -79,6 +287,38 @@ impl ByteRange {
     }
 }
 
+impl ClassRange for ByteRange {
+    type Value = u8;
+
+    const MIN: u8 = u8::MIN;
+    const MAX: u8 = u8::MAX;
+
+    #[inline]
+    fn new(start: u8, end: u8) -> ByteRange {
+        ByteRange::new(start, end)
+    }
+
+    #[inline]
+    fn start(self) -> u8 {
+        self.start
+    }
+
+    #[inline]
+    fn end(self) -> u8 {
+        self.end
+    }
+
+    #[inline]
+    fn successor(value: u8) -> Option<u8> {
+        value.checked_add(1)
+    }
+
+    #[inline]
+    fn predecessor(value: u8) -> Option<u8> {
+        value.checked_sub(1)
+    }
+}
+
 impl fmt::Debug for ByteRange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}..={}", self.start, self.end)
@@ -1,6 +1,7 @@
 use std::mem;
 
-use super::{Ast, Children};
+use super::{retain, Ast, Children, SpanMap};
+use crate::util::slice::NonEmpty;
 use bumpalo::Bump;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21,14 +22,45 @@ impl<'a> Concat<'a> {
         }
     }
 
+    /// Normalize `this`, whose children must already be normalized.
     #[inline]
-    pub fn normalize(this: &mut Ast<'a>) {
-        if let Ast::Concat(Concat { children }) = this {
-            children.iter_mut().for_each(Ast::normalize);
+    pub fn normalize(this: &mut Ast<'a>, spans: &mut SpanMap) {
+        let Ast::Concat(Concat { children }) = this else {
+            return;
+        };
 
-            if children.len().get() == 1 {
+        // Nothing can follow or precede something that matches nothing, but capture groups
+        // are kept so that backreferences to them still refer to a group.
+        if children.iter().any(Ast::is_fail)
+            && children.iter().all(|child| child.captures().is_empty())
+        {
+            for child in children.iter() {
+                spans.remove_tree(child);
+            }
+
+            *this = Ast::Fail;
+            return;
+        }
+
+        let len = retain(children, spans, |child| !child.is_empty());
+
+        match len {
+            0 => *this = Ast::Empty,
+            1 => {
+                spans.remove(children.first());
                 *this = mem::take(children.first_mut());
             }
+            len if len < children.len().get() => {
+                let Some(Concat { children }) = this.take_concat() else {
+                    unreachable!()
+                };
+                let children: &'a mut [Ast<'a>] = children.into();
+
+                *this = Ast::Concat(Concat {
+                    children: NonEmpty::new_mut(&mut children[..len]),
+                });
+            }
+            _ => {}
         }
     }
 }
@@ -0,0 +1,154 @@
+use std::{
+    fmt,
+    ops::{BitOr, BitOrAssign},
+};
+
+/// A set of flags that change how parts of a pattern are interpreted.
+///
+/// Flags are applied by the parser as it goes, so the nodes it produces
+/// already behave according to the flags that were active for them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Flags(u8);
+
+impl Flags {
+    pub const EMPTY: Flags = Flags(0);
+
+    /// `i`: ASCII letters match both their uppercase and lowercase forms. In Unicode mode,
+    /// scalar values match every one they are equivalent to under simple case folding.
+    pub const CASE_INSENSITIVE: Flags = Flags(1 << 0);
+    /// `m`: `^` and `$` match at the start and end of lines.
+    pub const MULTI_LINE: Flags = Flags(1 << 1);
+    /// `s`: `.` matches `\n`. Without it, `.` is parsed as a class of every other byte.
+    pub const DOT_MATCHES_NEW_LINE: Flags = Flags(1 << 2);
+    /// `x`: whitespace and `#` comments are ignored.
+    pub const IGNORE_WHITESPACE: Flags = Flags(1 << 3);
+    /// `u`: `.`, Perl classes and bracket classes match UTF-8 encoded scalar values
+    /// instead of bytes, and numeric escapes stand for scalar values.
+    pub const UNICODE: Flags = Flags(1 << 4);
+    /// `R`: in multi-line mode, `^` and `$` treat `\r`, `\n` and `\r\n` as line breaks.
+    pub const CRLF: Flags = Flags(1 << 5);
+
+    const ALL: [(Flags, u8); 6] = [
+        (Flags::CRLF, b'R'),
+        (Flags::CASE_INSENSITIVE, b'i'),
+        (Flags::MULTI_LINE, b'm'),
+        (Flags::DOT_MATCHES_NEW_LINE, b's'),
+        (Flags::UNICODE, b'u'),
+        (Flags::IGNORE_WHITESPACE, b'x'),
+    ];
+
+    /// Get the flag written as `letter`.
+    #[inline]
+    #[must_use]
+    pub const fn from_letter(letter: u8) -> Option<Flags> {
+        let mut i = 0;
+
+        while i < Flags::ALL.len() {
+            if Flags::ALL[i].1 == letter {
+                return Some(Flags::ALL[i].0);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Flags) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Flags) -> Flags {
+        Flags(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Flags) -> Flags {
+        Flags(self.0 & !other.0)
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+
+    #[inline]
+    fn bitor(self, rhs: Flags) -> Flags {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Flags) {
+        *self = self.union(rhs);
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Flags({self})")
+    }
+}
+
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, letter) in Flags::ALL {
+            if self.contains(flag) {
+                fmt::Write::write_char(f, letter as char)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A change to the active flags, written as `(?enable-disable)` or `(?enable-disable:...)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SetFlags {
+    pub enable: Flags,
+    pub disable: Flags,
+}
+
+impl SetFlags {
+    /// Get the flags that are active after applying this change to `flags`.
+    #[inline]
+    #[must_use]
+    pub const fn apply(self, flags: Flags) -> Flags {
+        flags.difference(self.disable).union(self.enable)
+    }
+}
+
+impl fmt::Display for SetFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.enable)?;
+
+        if !self.disable.is_empty() {
+            write!(f, "-{}", self.disable)?;
+        }
+
+        Ok(())
+    }
+}
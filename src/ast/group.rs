@@ -1,9 +1,15 @@
-use super::Ast;
+use super::{Ast, SetFlags, SpanMap};
 use bumpalo::Bump;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
 pub struct Group<'a> {
+    /// The flags changed for the contents of this group, as in `(?i:...)`.
+    pub flags: Option<SetFlags>,
+    /// The capture group this is, or `None` if it does not capture, as in `(?:...)`.
+    pub capture: Option<Capture<'a>>,
+    /// Whether this is an atomic group, as in `(?>...)`, which never backtracks into its
+    /// contents once they have matched.
+    pub atomic: bool,
     pub child: &'a mut Ast<'a>,
 }
 
@@ -14,14 +20,35 @@ impl<'a> Group<'a> {
         let child = self.child.clone_into(bump);
 
         Group {
+            flags: self.flags,
+            capture: self.capture.map(|capture| capture.clone_into(bump)),
+            atomic: self.atomic,
             child: bump.alloc(child),
         }
     }
 
+    /// Normalize `this`, whose child must already be normalized.
     #[inline]
-    pub fn normalize(this: &mut Ast<'a>) {
-        if let Ast::Group(Group { child }) = this {
-            child.normalize();
+    pub fn normalize(_this: &mut Ast<'a>, _spans: &mut SpanMap) {}
+}
+
+/// A capture group, which records the part of the input that its contents matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Capture<'a> {
+    /// The number of this group, counting from 1 in the order of the opening parentheses
+    /// of the capture groups in the pattern.
+    pub index: u32,
+    /// The name of this group, as in `(?<name>...)` or `(?P<name>...)`.
+    pub name: Option<&'a str>,
+}
+
+impl Capture<'_> {
+    #[inline]
+    #[must_use]
+    pub fn clone_into<'b>(&self, bump: &'b Bump) -> Capture<'b> {
+        Capture {
+            index: self.index,
+            name: self.name.map(|name| &*bump.alloc_str(name)),
         }
     }
 }
@@ -0,0 +1,121 @@
+use std::{fmt, str};
+
+use crate::unicode;
+
+/// An assertion about the position between two bytes, which matches the empty string.
+///
+/// Word characters are ASCII letters, digits and `_`, or for the Unicode variants, every
+/// scalar value matched by `\w` in Unicode mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Look {
+    /// `\A`, or `^` outside of multi-line mode: the start of the input.
+    Start,
+    /// `\z`, or `$` outside of multi-line mode: the end of the input.
+    End,
+    /// `\Z`, or `$` in syntaxes where it matches before a final newline: the end of the
+    /// input, or right before a `\n` that ends it.
+    EndBeforeNewline,
+    /// `^` in multi-line mode: the start of the input or right after a `\n`.
+    StartLine,
+    /// `$` in multi-line mode: the end of the input or right before a `\n`.
+    EndLine,
+    /// `^` in multi-line CRLF mode: the start of the input or right after a `\r` or `\n`,
+    /// but never between the two bytes of a `\r\n`.
+    StartLineCrlf,
+    /// `$` in multi-line CRLF mode: the end of the input or right before a `\r` or `\n`,
+    /// but never between the two bytes of a `\r\n`.
+    EndLineCrlf,
+    /// `\b`: a word character on exactly one side.
+    WordBoundary,
+    /// `\B`: a word character on both sides or on neither.
+    NotWordBoundary,
+    /// `\b` in Unicode mode.
+    WordBoundaryUnicode,
+    /// `\B` in Unicode mode.
+    NotWordBoundaryUnicode,
+    /// `\<`: a word character after, but not before.
+    WordStart,
+    /// `\>`: a word character before, but not after.
+    WordEnd,
+}
+
+impl Look {
+    /// Check whether this assertion holds at offset `at` in `haystack`.
+    #[must_use]
+    pub fn matches(self, haystack: &[u8], at: usize) -> bool {
+        let before = at.checked_sub(1).map(|i| haystack[i]);
+        let after = haystack.get(at).copied();
+
+        match self {
+            Look::Start => at == 0,
+            Look::End => at == haystack.len(),
+            Look::EndBeforeNewline => {
+                at == haystack.len() || (at + 1 == haystack.len() && after == Some(b'\n'))
+            }
+            Look::StartLine => before.is_none_or(|b| b == b'\n'),
+            Look::EndLine => after.is_none_or(|b| b == b'\n'),
+            Look::StartLineCrlf => match before {
+                None | Some(b'\n') => true,
+                Some(b'\r') => after != Some(b'\n'),
+                Some(_) => false,
+            },
+            Look::EndLineCrlf => match after {
+                None | Some(b'\r') => true,
+                Some(b'\n') => before != Some(b'\r'),
+                Some(_) => false,
+            },
+            Look::WordBoundary => is_word_byte(before) != is_word_byte(after),
+            Look::NotWordBoundary => is_word_byte(before) == is_word_byte(after),
+            Look::WordBoundaryUnicode => {
+                is_word_char_before(haystack, at) != is_word_char_after(haystack, at)
+            }
+            Look::NotWordBoundaryUnicode => {
+                is_word_char_before(haystack, at) == is_word_char_after(haystack, at)
+            }
+            Look::WordStart => !is_word_byte(before) && is_word_byte(after),
+            Look::WordEnd => is_word_byte(before) && !is_word_byte(after),
+        }
+    }
+}
+
+impl fmt::Display for Look {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Look::Start => r"\A",
+            Look::End => r"\z",
+            Look::EndBeforeNewline => r"\Z",
+            Look::StartLine => "(?m:^)",
+            Look::EndLine => "(?m:$)",
+            Look::StartLineCrlf => "(?mR:^)",
+            Look::EndLineCrlf => "(?mR:$)",
+            Look::WordBoundary => r"(?-u:\b)",
+            Look::NotWordBoundary => r"(?-u:\B)",
+            Look::WordBoundaryUnicode => r"(?u:\b)",
+            Look::NotWordBoundaryUnicode => r"(?u:\B)",
+            Look::WordStart => r"\<",
+            Look::WordEnd => r"\>",
+        })
+    }
+}
+
+#[inline]
+#[must_use]
+fn is_word_byte(byte: Option<u8>) -> bool {
+    byte.is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Check whether the UTF-8 encoded scalar value ending at `at` is a word character.
+#[must_use]
+fn is_word_char_before(haystack: &[u8], at: usize) -> bool {
+    (1..=at.min(4)).any(|len| {
+        str::from_utf8(&haystack[at - len..at]).is_ok_and(|s| s.chars().all(unicode::is_word_char))
+    })
+}
+
+/// Check whether the UTF-8 encoded scalar value starting at `at` is a word character.
+#[must_use]
+fn is_word_char_after(haystack: &[u8], at: usize) -> bool {
+    (1..=haystack.len().saturating_sub(at).min(4)).any(|len| {
+        str::from_utf8(&haystack[at..at + len]).is_ok_and(|s| s.chars().all(unicode::is_word_char))
+    })
+}
@@ -0,0 +1,77 @@
+use bumpalo::Bump;
+
+use super::{Ast, SpanMap};
+
+/// A lookahead or lookbehind, which matches the empty string if `child` matches right after
+/// or right before the current position, or if it does not when negated.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lookaround<'a> {
+    pub kind: LookaroundKind,
+    pub child: &'a mut Ast<'a>,
+}
+
+impl<'a> Lookaround<'a> {
+    #[inline]
+    #[must_use]
+    pub fn clone_into<'b>(&self, bump: &'b Bump) -> Lookaround<'b> {
+        let child = self.child.clone_into(bump);
+
+        Lookaround {
+            kind: self.kind,
+            child: bump.alloc(child),
+        }
+    }
+
+    /// Normalize `this`, whose child must already be normalized.
+    #[inline]
+    pub fn normalize(this: &mut Ast<'a>, spans: &mut SpanMap) {
+        let Ast::Lookaround(Lookaround { kind, child }) = this else {
+            return;
+        };
+
+        // Whether the child always or never matches decides the whole lookaround.
+        let matches = match child {
+            Ast::Empty => true,
+            Ast::Fail => false,
+            _ => return,
+        };
+
+        spans.remove(child);
+        *this = match matches != kind.is_negated() {
+            true => Ast::Empty,
+            false => Ast::Fail,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LookaroundKind {
+    /// `(?=...)`
+    Ahead,
+    /// `(?!...)`
+    NegativeAhead,
+    /// `(?<=...)`
+    Behind,
+    /// `(?<!...)`
+    NegativeBehind,
+}
+
+impl LookaroundKind {
+    #[inline]
+    #[must_use]
+    pub const fn is_negated(self) -> bool {
+        matches!(
+            self,
+            LookaroundKind::NegativeAhead | LookaroundKind::NegativeBehind
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_behind(self) -> bool {
+        matches!(
+            self,
+            LookaroundKind::Behind | LookaroundKind::NegativeBehind
+        )
+    }
+}
@@ -5,11 +5,12 @@ use std::{
 
 use bumpalo::Bump;
 
-use super::Ast;
+use super::{Ast, SpanMap};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Repeat<'a> {
     pub kind: RepeatKind,
+    pub greediness: Greediness,
     pub child: &'a mut Ast<'a>,
 }
 
@@ -21,29 +22,69 @@ impl<'a> Repeat<'a> {
 
         Repeat {
             kind: self.kind,
+            greediness: self.greediness,
             child: bump.alloc(child),
         }
     }
 
+    /// Normalize `this`, whose child must already be normalized.
     #[inline]
-    pub fn normalize(this: &mut Ast<'a>) {
-        if let Ast::Repeat(Repeat { kind, child }) = this {
+    pub fn normalize(this: &mut Ast<'a>, spans: &mut SpanMap) {
+        if let Ast::Repeat(Repeat {
+            kind,
+            greediness,
+            child,
+        }) = this
+        {
             *kind = kind.normalize();
 
+            // There is only one way to match a fixed number of times, except that a
+            // possessive repetition also never backtracks into its child.
+            if matches!(kind, RepeatKind::Exact(_)) && *greediness == Greediness::Lazy {
+                *greediness = Greediness::Greedy;
+            }
+
             match kind {
-                RepeatKind::Exact(0) => *this = Ast::Empty,
-                RepeatKind::Exact(1) => {
-                    // Overwrite `this` first to allow a tailcall.
+                // A capture group is kept even if it never matches, so that backreferences
+                // to it still refer to a group.
+                RepeatKind::Exact(0) if !child.captures().is_empty() => {}
+                RepeatKind::Exact(0) => {
+                    spans.remove_tree(child);
+                    *this = Ast::Empty;
+                }
+                RepeatKind::Exact(1) if *greediness != Greediness::Possessive => {
+                    spans.remove(child);
                     *this = mem::take(child);
-
-                    this.normalize()
                 }
-                _ => child.normalize(),
+                _ => {
+                    // A repetition of nothing can only match if it may be skipped.
+                    if child.is_fail() {
+                        spans.remove(child);
+                        *this = match kind.start() {
+                            0 => Ast::Empty,
+                            _ => Ast::Fail,
+                        };
+                    }
+                }
             }
         }
     }
 }
 
+/// How a repetition chooses how many times to match when there is more than one way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Greediness {
+    /// `*`: as many times as possible, giving repetitions back one at a time if the rest
+    /// of the pattern does not match.
+    #[default]
+    Greedy,
+    /// `*?`: as few times as possible, adding repetitions one at a time if the rest of
+    /// the pattern does not match.
+    Lazy,
+    /// `*+`: as many times as possible, never giving any repetitions back.
+    Possessive,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RepeatKind {
     Exact(u32),
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, ptr};
+
+use crate::util::span::Span;
+
+use super::Ast;
+
+/// The identity of an [`Ast`] node, derived from its address.
+///
+/// Nodes live in an arena and are never moved once they are part of a tree, so
+/// the address of a node identifies it for as long as the arena is alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    #[inline]
+    #[must_use]
+    pub fn of(node: &Ast<'_>) -> NodeId {
+        NodeId(ptr::from_ref(node) as usize)
+    }
+}
+
+/// A side-table mapping the nodes of an [`Ast`] to their spans in the pattern.
+///
+/// A span map is only meaningful for the tree it was built for. Use
+/// [`SpanMap::remap`] to carry it over to a copy made with [`Ast::clone_into`],
+/// and [`Ast::normalize_spanned`] to keep it up to date while normalizing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanMap {
+    spans: HashMap<NodeId, Span>,
+}
+
+impl SpanMap {
+    #[inline]
+    #[must_use]
+    pub fn new() -> SpanMap {
+        SpanMap::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Get the span of `node`, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, node: &Ast<'_>) -> Option<Span> {
+        self.spans.get(&NodeId::of(node)).copied()
+    }
+
+    /// Set the span of `node`, returning its previous span.
+    #[inline]
+    pub fn insert(&mut self, node: &Ast<'_>, span: Span) -> Option<Span> {
+        self.spans.insert(NodeId::of(node), span)
+    }
+
+    /// Remove the span of `node`, returning it.
+    #[inline]
+    pub fn remove(&mut self, node: &Ast<'_>) -> Option<Span> {
+        self.spans.remove(&NodeId::of(node))
+    }
+
+    /// Remove the spans of `node` and all of its descendants.
+    pub fn remove_tree(&mut self, node: &Ast<'_>) {
+        let mut stack = vec![node];
+
+        while let Some(node) = stack.pop() {
+            self.remove(node);
+            stack.extend(node.children().into_iter().flat_map(|c| c.iter()));
+        }
+    }
+
+    /// Build a span map for `to`, a structural copy of `from`.
+    ///
+    /// Nodes of `from` without a span are skipped, as are any nodes where
+    /// the two trees differ in shape.
+    #[must_use]
+    pub fn remap(&self, from: &Ast<'_>, to: &Ast<'_>) -> SpanMap {
+        let mut map = SpanMap::new();
+        let mut stack = vec![(from, to)];
+
+        while let Some((from, to)) = stack.pop() {
+            if let Some(span) = self.get(from) {
+                map.insert(to, span);
+            }
+
+            if let (Some(from), Some(to)) = (from.children(), to.children()) {
+                stack.extend(from.iter().zip(to.iter()));
+            }
+        }
+
+        map
+    }
+
+    /// Iterate over the ids of all nodes with a span, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, Span)> + '_ {
+        self.spans.iter().map(|(&id, &span)| (id, span))
+    }
+}
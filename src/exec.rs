@@ -0,0 +1,7 @@
+mod backtrack;
+mod error;
+mod pike;
+
+pub use backtrack::*;
+pub use error::*;
+pub use pike::*;
@@ -0,0 +1,680 @@
+use std::{mem, ops::Range, str};
+
+use super::Unsupported;
+use crate::{
+    ast::{Ast, BackrefCase, CharClass, Class, Greediness, Group, Look, Repeat},
+    parse::MatchKind,
+    unicode,
+};
+
+/// A matcher that compiles an [`Ast`] to a program and runs it by backtracking, trying
+/// alternatives and repetitions in order of preference, so that it finds leftmost-first or
+/// leftmost-longest matches.
+///
+/// Every kind of node is supported, except lookbehinds whose operand can match strings of
+/// unbounded length. A backreference to a group that has not matched never matches.
+///
+/// Matching takes exponential time in the worst case. The choices that remain to backtrack
+/// to are kept on the heap rather than the call stack, so a long input cannot overflow it,
+/// though the memory used grows with the length of the input.
+#[derive(Debug)]
+pub struct Backtracker<'t, 'a> {
+    insts: Vec<Inst<'t, 'a>>,
+    start: usize,
+    /// The number of capture groups, including the implicit group 0 for the whole match.
+    groups: usize,
+    /// The number of repetitions, each of which has its own registers.
+    repeats: usize,
+    kind: MatchKind,
+}
+
+/// An instruction of the program, which refers to others by their index.
+#[derive(Debug)]
+enum Inst<'t, 'a> {
+    Dot(usize),
+    Lit(&'t [u8], usize),
+    Class(&'t Class<'a>, usize),
+    CharClass(&'t CharClass<'a>, usize),
+    Look(Look, usize),
+    Backref(u32, BackrefCase, usize),
+    /// Continue with each of the targets, in order of preference.
+    Split(Vec<usize>),
+    /// Record the current offset in a capture slot.
+    Save(usize, usize),
+    /// Start counting the iterations of the repetition at the target.
+    Count(usize, usize),
+    /// Choose whether to match another iteration of a repetition.
+    Repeat(Loop),
+    /// Go back to the repetition at the target after an iteration.
+    Again(usize),
+    /// Only ever continue with the first way that the operand at the target matches, as
+    /// an atomic group does.
+    Atomic(usize),
+    /// End the operand of an atomic group.
+    AtomicEnd(usize),
+    /// Check whether the operand of a lookaround matches.
+    Lookaround(Lookaround),
+    /// End the operand of a lookaround.
+    LookaroundEnd,
+    Fail,
+    Match,
+}
+
+/// A repetition, compiled to a loop around its operand.
+#[derive(Debug)]
+struct Loop {
+    /// The index of the registers that hold the state of the repetition.
+    id: usize,
+    min: u32,
+    max: Option<u32>,
+    lazy: bool,
+    /// Whether the iterations are counted, which is only needed for a minimum or maximum.
+    counted: bool,
+    /// Whether the operand can match the empty string, in which case an optional iteration
+    /// that does must fail, or `(a*)*` would never end.
+    check_empty: bool,
+    body: usize,
+    next: usize,
+}
+
+#[derive(Debug)]
+struct Lookaround {
+    /// The shortest and longest lengths, in bytes, of the operand of a lookbehind, or
+    /// `None` for a lookahead.
+    behind: Option<(usize, usize)>,
+    negated: bool,
+    body: usize,
+    next: usize,
+}
+
+impl<'t, 'a> Backtracker<'t, 'a> {
+    /// Create a matcher for `ast` that finds leftmost-first matches.
+    #[inline]
+    pub fn new(ast: &'t Ast<'a>) -> Result<Backtracker<'t, 'a>, Unsupported> {
+        Backtracker::new_with(ast, MatchKind::LeftmostFirst)
+    }
+
+    /// Create a matcher for `ast` that chooses matches as `kind` says.
+    ///
+    /// A leftmost-longest search has to try every way to match at an offset that does not
+    /// reach the end of the input.
+    pub fn new_with(ast: &'t Ast<'a>, kind: MatchKind) -> Result<Backtracker<'t, 'a>, Unsupported> {
+        let mut compiler = Compiler {
+            insts: vec![Inst::Match],
+            groups: 1,
+            repeats: 0,
+        };
+        let start = compiler.compile(ast, 0)?;
+
+        Ok(Backtracker {
+            insts: compiler.insts,
+            start,
+            groups: compiler.groups,
+            repeats: compiler.repeats,
+            kind,
+        })
+    }
+
+    /// Check whether the pattern matches anywhere in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Check whether the pattern matches all of `haystack`.
+    #[must_use]
+    pub fn is_full_match(&self, haystack: &[u8]) -> bool {
+        self.search(haystack).run(0, true).is_some()
+    }
+
+    /// Find the leftmost match of the pattern in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn find(&self, haystack: &[u8]) -> Option<Range<usize>> {
+        self.captures(haystack)?.swap_remove(0)
+    }
+
+    /// Find the leftmost match of the pattern in `haystack`, along with the part of it
+    /// matched by each capture group.
+    ///
+    /// The whole match is group 0, and a group that did not participate in the match is
+    /// `None`. For leftmost-longest matches, the groups are those of the most preferred way
+    /// to match the longest span.
+    #[must_use]
+    pub fn captures(&self, haystack: &[u8]) -> Option<Vec<Option<Range<usize>>>> {
+        let mut search = self.search(haystack);
+
+        (0..=haystack.len()).find_map(|start| {
+            let end = search.run(start, false)?;
+            search.slots[0] = Some(start);
+            search.slots[1] = Some(end);
+
+            Some(
+                search
+                    .slots
+                    .chunks(2)
+                    .map(|slots| match *slots {
+                        [Some(start), Some(end)] => Some(start..end),
+                        _ => None,
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    fn search<'s, 'h>(&'s self, haystack: &'h [u8]) -> Search<'s, 't, 'a, 'h> {
+        Search {
+            insts: &self.insts,
+            start: self.start,
+            haystack,
+            slots: vec![None; self.groups * 2],
+            counts: vec![0; self.repeats],
+            starts: vec![0; self.repeats],
+            stack: Vec::new(),
+            longest: self.kind == MatchKind::LeftmostLongest,
+        }
+    }
+}
+
+struct Compiler<'t, 'a> {
+    insts: Vec<Inst<'t, 'a>>,
+    groups: usize,
+    repeats: usize,
+}
+
+impl<'t, 'a> Compiler<'t, 'a> {
+    fn push(&mut self, inst: Inst<'t, 'a>) -> usize {
+        self.insts.push(inst);
+        self.insts.len() - 1
+    }
+
+    /// Compile `ast` so that it continues with the instruction at `next`, returning the
+    /// index of its first instruction.
+    fn compile(&mut self, ast: &'t Ast<'a>, next: usize) -> Result<usize, Unsupported> {
+        Ok(match ast {
+            Ast::Empty | Ast::Flags(_) => next,
+            Ast::Fail => self.push(Inst::Fail),
+            Ast::Dot => self.push(Inst::Dot(next)),
+            Ast::Look(look) => self.push(Inst::Look(*look, next)),
+            Ast::Backref(backref) => {
+                self.groups = self.groups.max(backref.index as usize + 1);
+                self.push(Inst::Backref(backref.index, backref.case, next))
+            }
+            Ast::Lit(lit) => self.push(Inst::Lit(lit, next)),
+            Ast::Class(class) => self.push(Inst::Class(class, next)),
+            Ast::CharClass(class) => self.push(Inst::CharClass(class, next)),
+            Ast::Alt(alt) => {
+                let targets = alt
+                    .children
+                    .iter()
+                    .map(|child| self.compile(child, next))
+                    .collect::<Result<_, _>>()?;
+
+                self.push(Inst::Split(targets))
+            }
+            Ast::Concat(concat) => concat
+                .children
+                .iter()
+                .rev()
+                .try_fold(next, |next, child| self.compile(child, next))?,
+            Ast::Group(group) if group.atomic => {
+                let end = self.push(Inst::AtomicEnd(next));
+                let body = self.compile(group.child, end)?;
+                self.push(Inst::Atomic(body))
+            }
+            Ast::Group(Group {
+                capture: Some(capture),
+                child,
+                ..
+            }) => {
+                let slot = capture.index as usize * 2;
+                self.groups = self.groups.max(capture.index as usize + 1);
+
+                let end = self.push(Inst::Save(slot + 1, next));
+                let child = self.compile(child, end)?;
+                self.push(Inst::Save(slot, child))
+            }
+            Ast::Group(group) => self.compile(group.child, next)?,
+            Ast::Repeat(repeat) if repeat.greediness == Greediness::Possessive => {
+                let end = self.push(Inst::AtomicEnd(next));
+                let body = self.repeat(repeat, end)?;
+                self.push(Inst::Atomic(body))
+            }
+            Ast::Repeat(repeat) => self.repeat(repeat, next)?,
+            Ast::Lookaround(l) => {
+                let behind = if l.kind.is_behind() {
+                    let (min, max) = len_bounds(l.child);
+                    Some((min, max.ok_or(Unsupported::UnboundedLookbehind)?))
+                } else {
+                    None
+                };
+
+                let end = self.push(Inst::LookaroundEnd);
+                let body = self.compile(l.child, end)?;
+
+                self.push(Inst::Lookaround(Lookaround {
+                    behind,
+                    negated: l.kind.is_negated(),
+                    body,
+                    next,
+                }))
+            }
+        })
+    }
+
+    /// Compile `repeat` to a loop, whose greediness is only used to choose between another
+    /// iteration and `next`.
+    fn repeat(&mut self, repeat: &'t Repeat<'a>, next: usize) -> Result<usize, Unsupported> {
+        let id = self.repeats;
+        self.repeats += 1;
+
+        let (min, max) = (repeat.kind.start(), repeat.kind.end());
+        let counted = min > 0 || max.is_some();
+
+        let head = self.push(Inst::Fail);
+        let again = self.push(Inst::Again(head));
+        let body = self.compile(repeat.child, again)?;
+
+        self.insts[head] = Inst::Repeat(Loop {
+            id,
+            min,
+            max,
+            lazy: repeat.greediness == Greediness::Lazy,
+            counted,
+            check_empty: len_bounds(repeat.child).0 == 0,
+            body,
+            next,
+        });
+
+        Ok(if counted {
+            self.push(Inst::Count(id, head))
+        } else {
+            head
+        })
+    }
+}
+
+/// An entry of the backtracking stack.
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    /// Resume at an instruction and offset, as one of the choices that remain.
+    Choice(usize, usize),
+    /// Put back the previous value of a register, once the choices after it have failed.
+    Restore(Register),
+    /// The start of the operand of an atomic group, or of a lookaround if it holds the
+    /// index of its instruction, at an offset.
+    Barrier(Option<usize>, usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Register {
+    Slot(usize, Option<usize>),
+    Count(usize, u32),
+    Start(usize, usize),
+}
+
+struct Search<'s, 't, 'a, 'h> {
+    insts: &'s [Inst<'t, 'a>],
+    start: usize,
+    haystack: &'h [u8],
+    /// The start and end of each capture group in the current attempt.
+    slots: Vec<Option<usize>>,
+    /// The number of iterations of each repetition that have matched.
+    counts: Vec<u32>,
+    /// The offset that the current iteration of each repetition started at.
+    starts: Vec<usize>,
+    stack: Vec<Frame>,
+    /// Whether to look for the longest match rather than the first.
+    longest: bool,
+}
+
+impl Search<'_, '_, '_, '_> {
+    /// Run the program from offset `at`, returning the end of the first or longest match in
+    /// order of preference, which must be the end of the input if `full` is set.
+    fn run(&mut self, at: usize, full: bool) -> Option<usize> {
+        let haystack = self.haystack;
+
+        self.slots.fill(None);
+        self.stack.clear();
+
+        let (mut pc, mut at) = (self.start, at);
+        let mut longest: Option<(usize, Vec<Option<usize>>)> = None;
+
+        loop {
+            let next = match &self.insts[pc] {
+                Inst::Match if full && at < haystack.len() => None,
+                // No match can be longer than one that reaches the end of the input.
+                Inst::Match if self.longest && at < haystack.len() => {
+                    if longest.as_ref().is_none_or(|&(end, _)| end < at) {
+                        longest = Some((at, self.slots.clone()));
+                    }
+
+                    None
+                }
+                Inst::Match => return Some(at),
+                Inst::Fail => None,
+                &Inst::Dot(next) => (at < haystack.len()).then_some((next, at + 1)),
+                &Inst::Lit(lit, next) => haystack[at..]
+                    .starts_with(lit)
+                    .then_some((next, at + lit.len())),
+                &Inst::Class(class, next) => haystack
+                    .get(at)
+                    .is_some_and(|&b| class.contains(b))
+                    .then_some((next, at + 1)),
+                &Inst::CharClass(class, next) => decode(haystack, at)
+                    .filter(|&(c, _)| class.contains(c))
+                    .map(|(_, len)| (next, at + len)),
+                &Inst::Look(look, next) => look.matches(haystack, at).then_some((next, at)),
+                &Inst::Backref(index, case, next) => {
+                    self.backref(index, case, at).map(|len| (next, at + len))
+                }
+                Inst::Split(targets) => {
+                    let choices = targets[1..].iter().rev().map(|&t| Frame::Choice(t, at));
+                    self.stack.extend(choices);
+                    Some((targets[0], at))
+                }
+                &Inst::Save(slot, next) => {
+                    self.set(Register::Slot(slot, Some(at)));
+                    Some((next, at))
+                }
+                &Inst::Count(id, next) => {
+                    self.set(Register::Count(id, 0));
+                    Some((next, at))
+                }
+                Inst::Repeat(l) => Some(self.repeat(l, at)),
+                &Inst::Again(head) => {
+                    let Inst::Repeat(l) = &self.insts[head] else {
+                        unreachable!("an iteration goes back to its repetition");
+                    };
+
+                    self.again(l, at).then_some((head, at))
+                }
+                &Inst::Atomic(body) => {
+                    self.stack.push(Frame::Barrier(None, at));
+                    Some((body, at))
+                }
+                &Inst::AtomicEnd(next) => {
+                    self.cut();
+                    Some((next, at))
+                }
+                Inst::Lookaround(l) => {
+                    self.stack.push(Frame::Barrier(Some(pc), at));
+
+                    match l.behind {
+                        None => Some((l.body, at)),
+                        // Shorter operands are tried first, so the choices for longer ones
+                        // are pushed before them.
+                        Some((min, max)) if min <= at => {
+                            let choices = (min + 1..=max.min(at))
+                                .rev()
+                                .map(|len| Frame::Choice(l.body, at - len));
+                            self.stack.extend(choices);
+                            Some((l.body, at - min))
+                        }
+                        Some(_) => None,
+                    }
+                }
+                Inst::LookaroundEnd => self.lookaround_end(at),
+            };
+
+            match next.or_else(|| self.backtrack()) {
+                Some(next) => (pc, at) = next,
+                None => {
+                    let (end, slots) = longest?;
+                    self.slots = slots;
+                    return Some(end);
+                }
+            }
+        }
+    }
+
+    /// Match the text of the group `index` at `at`, returning its length.
+    fn backref(&self, index: u32, case: BackrefCase, at: usize) -> Option<usize> {
+        let slot = index as usize * 2;
+
+        // A reference to a group that has not matched never matches.
+        let (Some(start), Some(end)) = (self.slots[slot], self.slots[slot + 1]) else {
+            return None;
+        };
+
+        let text = &self.haystack[start..end];
+        let rest = &self.haystack[at..];
+
+        match case {
+            BackrefCase::Sensitive => rest.starts_with(text).then_some(text.len()),
+            BackrefCase::Ascii => rest
+                .get(..text.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(text))
+                .then_some(text.len()),
+            BackrefCase::Simple => fold_prefix(text, rest),
+        }
+    }
+
+    /// Choose between another iteration of `l` and the rest of the pattern at `at`,
+    /// returning the first choice and leaving the other to backtrack to.
+    fn repeat(&mut self, l: &Loop, at: usize) -> (usize, usize) {
+        let count = if l.counted { self.counts[l.id] } else { l.min };
+
+        if count < l.min {
+            return (l.body, at);
+        }
+
+        if l.max.is_some_and(|max| count >= max) {
+            return (l.next, at);
+        }
+
+        if l.check_empty {
+            self.set(Register::Start(l.id, at));
+        }
+
+        let (first, second) = if l.lazy {
+            (l.next, l.body)
+        } else {
+            (l.body, l.next)
+        };
+
+        self.stack.push(Frame::Choice(second, at));
+        (first, at)
+    }
+
+    /// Finish an iteration of `l` at `at`, returning whether it may continue.
+    fn again(&mut self, l: &Loop, at: usize) -> bool {
+        let count = if l.counted { self.counts[l.id] } else { l.min };
+
+        // Past the minimum, an iteration must consume input.
+        if l.check_empty && count >= l.min && at == self.starts[l.id] {
+            return false;
+        }
+
+        // Past the minimum of an unbounded repetition, the count no longer matters.
+        if l.counted && (count < l.min || l.max.is_some()) {
+            self.set(Register::Count(l.id, count + 1));
+        }
+
+        true
+    }
+
+    /// End the operand of the innermost lookaround at `at`, returning where to continue.
+    fn lookaround_end(&mut self, at: usize) -> Option<(usize, usize)> {
+        let (pc, start) = self.barrier();
+        let Inst::Lookaround(l) = &self.insts[pc.expect("a lookaround has a barrier")] else {
+            unreachable!("a barrier holds the index of a lookaround");
+        };
+
+        // The operand of a lookbehind must end where the lookaround is.
+        if l.behind.is_some() && at != start {
+            return None;
+        }
+
+        self.cut();
+
+        // Lookarounds are atomic: only whether the operand matches at all counts, though
+        // the groups it captured are kept unless the rest of the match fails.
+        (!l.negated).then_some((l.next, start))
+    }
+
+    /// Set a register, so that its previous value is put back when backtracking.
+    fn set(&mut self, register: Register) {
+        let old = match register {
+            Register::Slot(slot, value) => {
+                Register::Slot(slot, mem::replace(&mut self.slots[slot], value))
+            }
+            Register::Count(id, count) => {
+                Register::Count(id, mem::replace(&mut self.counts[id], count))
+            }
+            Register::Start(id, at) => Register::Start(id, mem::replace(&mut self.starts[id], at)),
+        };
+
+        self.stack.push(Frame::Restore(old));
+    }
+
+    /// Get the innermost barrier, as the instruction it holds and its offset.
+    fn barrier(&self) -> (Option<usize>, usize) {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(|&frame| match frame {
+                Frame::Barrier(pc, at) => Some((pc, at)),
+                _ => None,
+            })
+            .expect("the operand of an atomic group or lookaround has a barrier")
+    }
+
+    /// Drop the innermost barrier and every choice after it, keeping the registers to put
+    /// back.
+    fn cut(&mut self) {
+        let barrier = self
+            .stack
+            .iter()
+            .rposition(|frame| matches!(frame, Frame::Barrier(..)))
+            .expect("the operand of an atomic group or lookaround has a barrier");
+
+        let mut len = barrier;
+
+        for i in barrier + 1..self.stack.len() {
+            if let Frame::Restore(_) = self.stack[i] {
+                self.stack[len] = self.stack[i];
+                len += 1;
+            }
+        }
+
+        self.stack.truncate(len);
+    }
+
+    /// Go back to the most recent choice that remains, returning where to continue, or
+    /// `None` if there are none.
+    fn backtrack(&mut self) -> Option<(usize, usize)> {
+        while let Some(frame) = self.stack.pop() {
+            match frame {
+                Frame::Choice(pc, at) => return Some((pc, at)),
+                Frame::Restore(Register::Slot(slot, value)) => self.slots[slot] = value,
+                Frame::Restore(Register::Count(id, count)) => self.counts[id] = count,
+                Frame::Restore(Register::Start(id, at)) => self.starts[id] = at,
+                // The operand of a negative lookaround did not match, so it holds.
+                Frame::Barrier(Some(pc), at) => match &self.insts[pc] {
+                    Inst::Lookaround(l) if l.negated => return Some((l.next, at)),
+                    _ => {}
+                },
+                Frame::Barrier(None, _) => {}
+            }
+        }
+
+        None
+    }
+}
+
+/// Get the shortest and longest lengths, in bytes, of the strings that `ast` matches, where
+/// the longest is `None` if it is unbounded.
+#[must_use]
+fn len_bounds(ast: &Ast<'_>) -> (usize, Option<usize>) {
+    match ast {
+        Ast::Empty | Ast::Fail | Ast::Flags(_) | Ast::Look(_) | Ast::Lookaround(_) => (0, Some(0)),
+        Ast::Dot | Ast::Class(_) => (1, Some(1)),
+        Ast::CharClass(class) => (
+            class
+                .iter()
+                .map(|range| range.start.len_utf8())
+                .min()
+                .unwrap(),
+            class.iter().map(|range| range.end.len_utf8()).max(),
+        ),
+        Ast::Backref(_) => (0, None),
+        Ast::Lit(lit) => (lit.len().get(), Some(lit.len().get())),
+        Ast::Alt(alt) => alt
+            .children
+            .iter()
+            .map(len_bounds)
+            .fold((usize::MAX, Some(0)), |a, b| {
+                (a.0.min(b.0), a.1.zip(b.1).map(|(a, b)| a.max(b)))
+            }),
+        Ast::Concat(concat) => concat
+            .children
+            .iter()
+            .map(len_bounds)
+            .fold((0, Some(0)), |a, b| {
+                (
+                    a.0.saturating_add(b.0),
+                    a.1.zip(b.1).and_then(|(a, b)| a.checked_add(b)),
+                )
+            }),
+        Ast::Group(group) => len_bounds(group.child),
+        Ast::Repeat(repeat) => {
+            let (min, max) = len_bounds(repeat.child);
+            let count = |n: u32| usize::try_from(n).unwrap_or(usize::MAX);
+
+            let max = match (max, repeat.kind.end()) {
+                (Some(0), _) => Some(0),
+                (Some(max), Some(end)) => max.checked_mul(count(end)),
+                _ => None,
+            };
+
+            (min.saturating_mul(count(repeat.kind.start())), max)
+        }
+    }
+}
+
+/// Get the length of the start of `haystack` that is equal to `text` under simple case
+/// folding, if there is one. Bytes that are not valid UTF-8 must be the same.
+#[must_use]
+fn fold_prefix(text: &[u8], haystack: &[u8]) -> Option<usize> {
+    let (mut i, mut j) = (0, 0);
+
+    while i < text.len() {
+        match (decode(text, i), decode(haystack, j)) {
+            (Some((a, a_len)), Some((b, b_len)))
+                if a == b || unicode::simple_case_fold(a).contains(&b) =>
+            {
+                i += a_len;
+                j += b_len;
+            }
+            (None, _) if haystack.get(j) == Some(&text[i]) => {
+                i += 1;
+                j += 1;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(j)
+}
+
+/// Decode the UTF-8 encoded scalar value starting at `at`, along with its length, if there
+/// is a valid one.
+#[must_use]
+fn decode(haystack: &[u8], at: usize) -> Option<(char, usize)> {
+    let len = match *haystack.get(at)? {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => return None,
+    };
+
+    let c = str::from_utf8(haystack.get(at..at + len)?)
+        .ok()?
+        .chars()
+        .next()?;
+
+    Some((c, len))
+}
@@ -0,0 +1,35 @@
+use std::{error::Error, fmt};
+
+/// A kind of node or pattern that an engine cannot run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unsupported {
+    /// A lookbehind whose operand can match strings of unbounded length, as in `(?<=a+)`.
+    UnboundedLookbehind,
+    /// A lookahead or lookbehind, as in `(?=a)`.
+    Lookaround,
+    /// An atomic group, as in `(?>a)`.
+    AtomicGroup,
+    /// A possessive repetition, as in `a*+`.
+    PossessiveRepeat,
+    /// A backreference, as in `\1`.
+    Backreference,
+    /// A pattern that compiles to more instructions than the engine allows, as nested
+    /// counted repetitions like `(a{1000}){1000}` can.
+    TooLarge,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unsupported::UnboundedLookbehind => "lookbehind of unbounded length is not supported",
+            Unsupported::Lookaround => "lookaround is not supported",
+            Unsupported::AtomicGroup => "atomic group is not supported",
+            Unsupported::PossessiveRepeat => "possessive repetition is not supported",
+            Unsupported::Backreference => "backreference is not supported",
+            Unsupported::TooLarge => "pattern is too large",
+        })
+    }
+}
+
+impl Error for Unsupported {}
@@ -0,0 +1,373 @@
+use std::{cmp::Reverse, mem, ops::Range};
+
+use super::Unsupported;
+use crate::{
+    ast::{Ast, ByteRange, CharClass, Greediness, Group, Look, Repeat},
+    parse::MatchKind,
+    unicode::Utf8Sequences,
+};
+
+/// A matcher that compiles an [`Ast`] to a Thompson NFA and simulates it, so that it finds
+/// leftmost-first or leftmost-longest matches in time linear in the length of the input.
+///
+/// Nodes that can only be matched by backtracking are not supported: lookarounds, atomic
+/// groups, possessive repetitions and backreferences. Counted repetitions are expanded, so
+/// the size of the NFA grows with their counts, up to [`PikeVm::MAX_INSTS`] instructions,
+/// and classes of scalar values are lowered to the byte sequences of their UTF-8 encodings.
+#[derive(Debug, Clone)]
+pub struct PikeVm {
+    insts: Vec<Inst>,
+    start: usize,
+    /// The number of capture groups, including the implicit group 0 for the whole match.
+    groups: usize,
+    kind: MatchKind,
+}
+
+/// An instruction of the NFA, which refers to others by their index.
+#[derive(Debug, Clone)]
+enum Inst {
+    /// Consume a byte in one of the ranges.
+    Bytes(Vec<ByteRange>, usize),
+    /// Continue with each of the targets, in order of preference.
+    Split(Vec<usize>),
+    /// Continue if the assertion holds.
+    Look(Look, usize),
+    /// Record the current offset in a capture slot.
+    Save(usize, usize),
+    Fail,
+    Match,
+}
+
+impl PikeVm {
+    /// The most instructions that a compiled NFA may have.
+    pub const MAX_INSTS: usize = 1 << 20;
+
+    /// Compile a matcher for `ast` that finds leftmost-first matches.
+    ///
+    /// Fails with [`Unsupported::TooLarge`] if the NFA would have more than
+    /// [`PikeVm::MAX_INSTS`] instructions.
+    #[inline]
+    pub fn new(ast: &Ast<'_>) -> Result<PikeVm, Unsupported> {
+        PikeVm::new_with(ast, MatchKind::LeftmostFirst)
+    }
+
+    /// Compile a matcher for `ast` that chooses matches as `kind` says.
+    ///
+    /// Fails with [`Unsupported::TooLarge`] if the NFA would have more than
+    /// [`PikeVm::MAX_INSTS`] instructions.
+    pub fn new_with(ast: &Ast<'_>, kind: MatchKind) -> Result<PikeVm, Unsupported> {
+        let mut insts = vec![Inst::Match];
+        let end = push(&mut insts, Inst::Save(1, 0))?;
+        let body = compile(&mut insts, ast, end)?;
+        let start = push(&mut insts, Inst::Save(0, body))?;
+
+        let groups = ast
+            .captures()
+            .iter()
+            .map(|capture| capture.index as usize + 1)
+            .fold(1, usize::max);
+
+        Ok(PikeVm {
+            insts,
+            start,
+            groups,
+            kind,
+        })
+    }
+
+    /// Check whether the pattern matches anywhere in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Find the leftmost match of the pattern in `haystack`.
+    #[inline]
+    #[must_use]
+    pub fn find(&self, haystack: &[u8]) -> Option<Range<usize>> {
+        self.captures(haystack)?.swap_remove(0)
+    }
+
+    /// Find the leftmost match of the pattern in `haystack`, along with the part of it
+    /// matched by each capture group.
+    ///
+    /// The whole match is group 0, and a group that did not participate in the match is
+    /// `None`. For leftmost-longest matches, the groups are those of the most preferred way
+    /// to match the longest span.
+    #[must_use]
+    pub fn captures(&self, haystack: &[u8]) -> Option<Vec<Option<Range<usize>>>> {
+        let mut current = Threads::new(self.insts.len());
+        let mut next = Threads::new(self.insts.len());
+        let mut matched = None;
+
+        for at in 0..=haystack.len() {
+            // A new attempt starts at each offset until a match is found, with a lower
+            // preference than the attempts that started before it.
+            if matched.is_none() {
+                let slots = vec![None; self.groups * 2];
+                self.add(&mut current, self.start, haystack, at, slots);
+            }
+
+            if current.pcs.is_empty() && matched.is_some() {
+                break;
+            }
+
+            for (pc, slots) in current.pcs.iter().zip(current.slots.drain(..)) {
+                // Threads are in order of where they started, and those that started after
+                // a match can no longer be the leftmost.
+                if matched
+                    .as_ref()
+                    .is_some_and(|best: &Vec<_>| slots[0] > best[0])
+                {
+                    break;
+                }
+
+                match &self.insts[*pc] {
+                    Inst::Bytes(ranges, target) => {
+                        let consumed = haystack.get(at).is_some_and(|&b| {
+                            ranges.iter().any(|range| range.to_inclusive().contains(&b))
+                        });
+
+                        if consumed {
+                            self.add(&mut next, *target, haystack, at + 1, slots);
+                        }
+                    }
+                    Inst::Match if self.kind == MatchKind::LeftmostFirst => {
+                        // Every thread after this one is less preferred.
+                        matched = Some(slots);
+                        break;
+                    }
+                    // A match replaces one that starts later or that is shorter, but not a
+                    // more preferred one of the same span found earlier in this step.
+                    Inst::Match => {
+                        if matched.as_ref().is_none_or(|best| {
+                            (slots[0], Reverse(slots[1])) < (best[0], Reverse(best[1]))
+                        }) {
+                            matched = Some(slots);
+                        }
+                    }
+                    _ => unreachable!("only byte and match instructions are threads"),
+                }
+            }
+
+            current.clear();
+            (current, next) = (next, current);
+        }
+
+        let slots = matched?;
+
+        Some(
+            slots
+                .chunks(2)
+                .map(|slots| match *slots {
+                    [Some(start), Some(end)] => Some(start..end),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
+    /// Add a thread at `pc` to `threads`, following every instruction that does not
+    /// consume input in order of preference.
+    fn add(
+        &self,
+        threads: &mut Threads,
+        pc: usize,
+        haystack: &[u8],
+        at: usize,
+        mut slots: Vec<Option<usize>>,
+    ) {
+        let mut stack = vec![Frame::Explore(pc)];
+
+        while let Some(frame) = stack.pop() {
+            let pc = match frame {
+                Frame::Explore(pc) => pc,
+                Frame::Restore(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
+
+            if !threads.visit(pc) {
+                continue;
+            }
+
+            match &self.insts[pc] {
+                Inst::Bytes(..) | Inst::Match => threads.push(pc, slots.clone()),
+                Inst::Split(targets) => {
+                    stack.extend(targets.iter().rev().map(|&t| Frame::Explore(t)))
+                }
+                Inst::Look(look, target) => {
+                    if look.matches(haystack, at) {
+                        stack.push(Frame::Explore(*target));
+                    }
+                }
+                Inst::Save(slot, target) => {
+                    stack.push(Frame::Restore(*slot, slots[*slot]));
+                    stack.push(Frame::Explore(*target));
+                    slots[*slot] = Some(at);
+                }
+                Inst::Fail => {}
+            }
+        }
+    }
+}
+
+enum Frame {
+    Explore(usize),
+    /// Put back the value of a capture slot once every path through a `Save` is explored.
+    Restore(usize, Option<usize>),
+}
+
+/// The threads at one offset of the input, in order of preference.
+struct Threads {
+    /// Whether each instruction has been visited at this offset.
+    visited: Vec<bool>,
+    pcs: Vec<usize>,
+    slots: Vec<Vec<Option<usize>>>,
+}
+
+impl Threads {
+    fn new(len: usize) -> Threads {
+        Threads {
+            visited: vec![false; len],
+            pcs: Vec::new(),
+            slots: Vec::new(),
+        }
+    }
+
+    /// Mark `pc` as visited, returning whether it was not already.
+    fn visit(&mut self, pc: usize) -> bool {
+        !mem::replace(&mut self.visited[pc], true)
+    }
+
+    fn push(&mut self, pc: usize, slots: Vec<Option<usize>>) {
+        self.pcs.push(pc);
+        self.slots.push(slots);
+    }
+
+    fn clear(&mut self) {
+        self.visited.fill(false);
+        self.pcs.clear();
+        self.slots.clear();
+    }
+}
+
+fn push(insts: &mut Vec<Inst>, inst: Inst) -> Result<usize, Unsupported> {
+    if insts.len() >= PikeVm::MAX_INSTS {
+        return Err(Unsupported::TooLarge);
+    }
+
+    insts.push(inst);
+    Ok(insts.len() - 1)
+}
+
+/// Compile `ast` so that it continues with the instruction at `next`, returning the index
+/// of its first instruction.
+fn compile(insts: &mut Vec<Inst>, ast: &Ast<'_>, next: usize) -> Result<usize, Unsupported> {
+    Ok(match ast {
+        Ast::Empty | Ast::Flags(_) => next,
+        Ast::Fail => push(insts, Inst::Fail)?,
+        Ast::Dot => push(insts, Inst::Bytes(vec![ByteRange::new(0, 0xFF)], next))?,
+        Ast::Look(look) => push(insts, Inst::Look(*look, next))?,
+        Ast::Lit(lit) => lit.iter().rev().try_fold(next, |next, &b| {
+            push(insts, Inst::Bytes(vec![ByteRange::new(b, b)], next))
+        })?,
+        Ast::Class(class) => push(insts, Inst::Bytes(class.to_vec(), next))?,
+        Ast::CharClass(class) => compile_chars(insts, class, next)?,
+        Ast::Alt(alt) => {
+            let targets = alt
+                .children
+                .iter()
+                .map(|child| compile(insts, child, next))
+                .collect::<Result<_, _>>()?;
+
+            push(insts, Inst::Split(targets))?
+        }
+        Ast::Concat(concat) => concat
+            .children
+            .iter()
+            .rev()
+            .try_fold(next, |next, child| compile(insts, child, next))?,
+        Ast::Group(group) if group.atomic => return Err(Unsupported::AtomicGroup),
+        Ast::Group(Group {
+            capture: Some(capture),
+            child,
+            ..
+        }) => {
+            let slot = capture.index as usize * 2;
+            let end = push(insts, Inst::Save(slot + 1, next))?;
+            let child = compile(insts, child, end)?;
+
+            push(insts, Inst::Save(slot, child))?
+        }
+        Ast::Group(group) => compile(insts, group.child, next)?,
+        Ast::Repeat(repeat) => compile_repeat(insts, repeat, next)?,
+        Ast::Lookaround(_) => return Err(Unsupported::Lookaround),
+        Ast::Backref(_) => return Err(Unsupported::Backreference),
+    })
+}
+
+/// Compile `class` to an alternation of the byte sequences that match the UTF-8 encodings
+/// of its scalar values.
+fn compile_chars(
+    insts: &mut Vec<Inst>,
+    class: &CharClass<'_>,
+    next: usize,
+) -> Result<usize, Unsupported> {
+    let mut targets: Vec<_> = Utf8Sequences::new(class)
+        .map(|seq| {
+            seq.as_slice().iter().rev().try_fold(next, |next, &range| {
+                push(insts, Inst::Bytes(vec![range], next))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    match targets.len() {
+        1 => Ok(targets.pop().unwrap()),
+        _ => push(insts, Inst::Split(targets)),
+    }
+}
+
+fn compile_repeat(
+    insts: &mut Vec<Inst>,
+    repeat: &Repeat<'_>,
+    next: usize,
+) -> Result<usize, Unsupported> {
+    let Repeat {
+        kind,
+        greediness,
+        child,
+    } = repeat;
+
+    if *greediness == Greediness::Possessive {
+        return Err(Unsupported::PossessiveRepeat);
+    }
+
+    // Choose between another iteration and the rest of the pattern.
+    let split = |again: usize| match greediness {
+        Greediness::Lazy => Inst::Split(vec![next, again]),
+        _ => Inst::Split(vec![again, next]),
+    };
+
+    // The optional iterations are compiled first, since each continues with the next.
+    let mut entry = match kind.end() {
+        None => {
+            let entry = push(insts, Inst::Fail)?;
+            let again = compile(insts, child, entry)?;
+            insts[entry] = split(again);
+            entry
+        }
+        Some(end) => (kind.start()..end).try_fold(next, |entry, _| {
+            let again = compile(insts, child, entry)?;
+            push(insts, split(again))
+        })?,
+    };
+
+    for _ in 0..kind.start() {
+        entry = compile(insts, child, entry)?;
+    }
+
+    Ok(entry)
+}
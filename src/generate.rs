@@ -0,0 +1,180 @@
+use crate::{
+    ast::{Ast, Group},
+    exec::{Backtracker, Unsupported},
+};
+
+/// A generator of pseudo-random strings that an [`Ast`] matches in full.
+///
+/// Strings are built by choosing among the alternatives, repetition counts and bytes that
+/// the pattern allows, with backreferences repeating the text their group produced. Each
+/// one is then checked with a [`Backtracker`], so that assertions, lookarounds, atomic
+/// groups and possessive repetitions are honored by discarding the strings that they rule
+/// out.
+///
+/// Nested repetitions multiply, so a string is abandoned once it grows past
+/// [`Generator::MAX_LEN`] bytes or takes more than [`Generator::MAX_STEPS`] nodes to build.
+#[derive(Debug)]
+pub struct Generator<'t, 'a> {
+    ast: &'t Ast<'a>,
+    checker: Backtracker<'t, 'a>,
+    /// The state of the xorshift generator, which is never zero.
+    state: u64,
+    /// The number of nodes visited while building the current string.
+    steps: usize,
+}
+
+impl<'t, 'a> Generator<'t, 'a> {
+    /// The most times that a repetition is repeated beyond its minimum.
+    pub const MAX_EXTRA_REPEATS: u32 = 4;
+
+    /// The number of strings built by [`Generator::generate`] before it gives up.
+    pub const ATTEMPTS: usize = 100;
+
+    /// The longest string, in bytes, that is generated.
+    pub const MAX_LEN: usize = 1 << 16;
+
+    /// The most nodes visited while building a single string.
+    pub const MAX_STEPS: usize = 1 << 18;
+
+    /// Create a generator for `ast`, whose choices are determined by `seed`.
+    pub fn new(ast: &'t Ast<'a>, seed: u64) -> Result<Generator<'t, 'a>, Unsupported> {
+        Ok(Generator {
+            ast,
+            checker: Backtracker::new(ast)?,
+            state: seed | 1,
+            steps: 0,
+        })
+    }
+
+    /// Generate a string that the pattern matches in full, or `None` if none was found.
+    #[must_use]
+    pub fn generate(&mut self) -> Option<Vec<u8>> {
+        (0..Self::ATTEMPTS).find_map(|_| {
+            let mut out = Vec::new();
+            let mut slots = Vec::new();
+
+            self.steps = 0;
+            let built = self.node(self.ast, &mut out, &mut slots) && out.len() <= Self::MAX_LEN;
+
+            (built && self.checker.is_full_match(&out)).then_some(out)
+        })
+    }
+
+    /// Append a string that `ast` may match to `out`, returning whether there is one.
+    ///
+    /// `slots` holds the start and end in `out` of the text produced by each capture group.
+    /// Once `out` is too long or too many nodes have been visited, there is none.
+    fn node(&mut self, ast: &Ast<'_>, out: &mut Vec<u8>, slots: &mut Vec<Option<usize>>) -> bool {
+        self.steps += 1;
+
+        if out.len() > Self::MAX_LEN || self.steps > Self::MAX_STEPS {
+            return false;
+        }
+
+        match ast {
+            Ast::Empty | Ast::Flags(_) | Ast::Look(_) | Ast::Lookaround(_) => true,
+            Ast::Fail => false,
+            Ast::Dot => {
+                out.push(self.below(0x100) as u8);
+                true
+            }
+            Ast::Lit(lit) => {
+                out.extend_from_slice(lit);
+                true
+            }
+            Ast::Class(class) => {
+                let len = class.iter().map(|range| range.iter().len() as u64).sum();
+                let mut n = self.below(len);
+
+                for range in class.iter() {
+                    let size = range.iter().len() as u64;
+
+                    if n < size {
+                        out.push(range.start + n as u8);
+                        break;
+                    }
+
+                    n -= size;
+                }
+
+                true
+            }
+            Ast::CharClass(class) => {
+                let len = class.iter().map(|range| u64::from(range.count())).sum();
+                let mut n = self.below(len);
+
+                for range in class.iter() {
+                    let size = u64::from(range.count());
+
+                    if n < size {
+                        let c = range.to_inclusive().nth(n as usize).unwrap();
+                        out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        break;
+                    }
+
+                    n -= size;
+                }
+
+                true
+            }
+            Ast::Alt(alt) => {
+                let i = self.below(alt.children.len().get() as u64) as usize;
+                self.node(&alt.children[i], out, slots)
+            }
+            Ast::Concat(concat) => concat
+                .children
+                .iter()
+                .all(|child| self.node(child, out, slots)),
+            Ast::Group(Group {
+                capture: Some(capture),
+                child,
+                ..
+            }) => {
+                let slot = capture.index as usize * 2;
+                let start = out.len();
+
+                if !self.node(child, out, slots) {
+                    return false;
+                }
+
+                if slots.len() < slot + 2 {
+                    slots.resize(slot + 2, None);
+                }
+
+                (slots[slot], slots[slot + 1]) = (Some(start), Some(out.len()));
+                true
+            }
+            Ast::Group(group) => self.node(group.child, out, slots),
+            Ast::Repeat(repeat) => {
+                let start = repeat.kind.start();
+                let extra = match repeat.kind.end() {
+                    Some(end) => (end - start).min(Self::MAX_EXTRA_REPEATS),
+                    None => Self::MAX_EXTRA_REPEATS,
+                };
+                let extra = self.below(u64::from(extra) + 1) as u32;
+
+                (0..start + extra).all(|_| self.node(repeat.child, out, slots))
+            }
+            Ast::Backref(backref) => {
+                let slot = backref.index as usize * 2;
+
+                match slots.get(slot..slot + 2) {
+                    Some(&[Some(start), Some(end)]) => {
+                        out.extend_from_within(start..end);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Get a pseudo-random number less than `n`, or 0 if `n` is 0.
+    fn below(&mut self, n: u64) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        self.state.checked_rem(n).unwrap_or(0)
+    }
+}
@@ -1,5 +1,14 @@
 pub mod ast;
+/// Engines that match an [`Ast`](ast::Ast) against input.
+pub mod exec;
+/// Generation of strings that an [`Ast`](ast::Ast) matches.
+pub mod generate;
 pub mod hir;
 
+/// Parsing of patterns into an [`Ast`](ast::Ast).
+pub mod parse;
+
+/// Unicode character tables, and the UTF-8 encoding of sets of scalar values.
+pub mod unicode;
 /// Miscellaneous utilities.
 pub mod util;
@@ -0,0 +1,1181 @@
+mod class;
+mod dialect;
+mod error;
+mod escape;
+mod glob;
+mod grammar;
+mod limits;
+mod sql;
+mod syntax;
+
+pub use dialect::*;
+pub use error::*;
+pub use glob::*;
+pub use grammar::*;
+pub use limits::*;
+pub use sql::*;
+pub use syntax::*;
+
+use class::NamedClass;
+use dialect::Token;
+
+use std::{collections::HashMap, str};
+
+use bumpalo::Bump;
+use winnow::{
+    ascii::digit0,
+    combinator::opt,
+    error::ErrMode,
+    stream::{Located, Location, Stream},
+    PResult, Parser as _,
+};
+
+use crate::{
+    ast::{
+        canonicalize_ranges, Alt, Ast, Backref, ByteRange, Capture, CharRange, Children, Class,
+        Concat, Flags, Greediness, Group, Lit, Look, Lookaround, LookaroundKind, Repeat,
+        RepeatKind, SetFlags, SpanMap,
+    },
+    unicode,
+    util::{slice::NonEmpty, span::Span},
+};
+
+type Input<'i> = Located<&'i [u8]>;
+
+/// Parse `pattern` into an [`Ast`] allocated in `bump`.
+pub fn parse<'b>(pattern: &[u8], bump: &'b Bump) -> Result<Ast<'b>, ParseError> {
+    parse_with(pattern, Syntax::DEFAULT, bump)
+}
+
+/// Parse `pattern`, which is written in `syntax`, into an [`Ast`] allocated in `bump`.
+pub fn parse_with<'b>(
+    pattern: &[u8],
+    syntax: Syntax,
+    bump: &'b Bump,
+) -> Result<Ast<'b>, ParseError> {
+    let mut input = Located::new(pattern);
+
+    Parser::new(bump, syntax, false)
+        .pattern(&mut input)
+        .map_err(|err| err.into_inner().expect("input is never partial"))
+}
+
+/// Parse `pattern`, which is written in `syntax`, into an [`Ast`] allocated in `bump`,
+/// recovering from as many errors as possible.
+///
+/// Every error is returned along with a best-effort [`Ast`], in which each part of the
+/// pattern that could not be parsed is replaced by [`Ast::Fail`]. Parsing resumes at the
+/// next `|` or `)` after an error, or after the `)` if it has no matching `(`. Exceeding
+/// the node or arena [`Limits`] stops parsing entirely, in which case the [`Ast`] is just
+/// [`Ast::Fail`].
+pub fn parse_recovering<'b>(
+    pattern: &[u8],
+    syntax: Syntax,
+    bump: &'b Bump,
+) -> (Ast<'b>, Vec<ParseError>) {
+    let mut input = Located::new(pattern);
+    let mut parser = Parser::new(bump, syntax, false);
+    parser.errors = Some(Vec::new());
+
+    let result = parser.pattern(&mut input);
+    let mut errors = parser.errors.unwrap_or_default();
+
+    match result {
+        Ok(ast) => (ast, errors),
+        Err(err) => {
+            errors.push(err.into_inner().expect("input is never partial"));
+            (Ast::Fail, errors)
+        }
+    }
+}
+
+/// Parse `pattern` into an [`Ast`] allocated in `bump`, along with the span of every node.
+///
+/// The root is allocated in `bump` as well, so that it has a stable identity in the
+/// returned [`SpanMap`].
+pub fn parse_spanned<'b>(
+    pattern: &[u8],
+    bump: &'b Bump,
+) -> Result<(&'b mut Ast<'b>, SpanMap), ParseError> {
+    parse_spanned_with(pattern, Syntax::DEFAULT, bump)
+}
+
+/// Parse `pattern`, which is written in `syntax`, into an [`Ast`] allocated in `bump`,
+/// along with the span of every node.
+pub fn parse_spanned_with<'b>(
+    pattern: &[u8],
+    syntax: Syntax,
+    bump: &'b Bump,
+) -> Result<(&'b mut Ast<'b>, SpanMap), ParseError> {
+    let mut input = Located::new(pattern);
+    let mut parser = Parser::new(bump, syntax, true);
+
+    let ast = parser
+        .pattern(&mut input)
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+    let ast = parser.alloc(ast, Span::new(0, pattern.len()));
+
+    Ok((ast, parser.spans.unwrap_or_default()))
+}
+
+struct Parser<'b> {
+    bump: &'b Bump,
+    /// The spans of nodes that have been placed in the arena, if they are being recorded.
+    spans: Option<SpanMap>,
+    syntax: Syntax,
+    /// The flags active at the current position.
+    flags: Flags,
+    /// The number of groups and bracket classes enclosing the current position.
+    depth: u32,
+    /// The number of nodes that have been placed in the arena.
+    nodes: usize,
+    /// The number of bytes allocated by the arena before parsing started.
+    arena_start: usize,
+    /// The errors that have been recovered from, if errors are being recovered from.
+    errors: Option<Vec<ParseError>>,
+    /// The number of capture groups opened so far.
+    captures: u32,
+    /// The indices of the named capture groups opened so far.
+    names: HashMap<&'b str, u32>,
+    /// The indices of the capture groups enclosing the current position.
+    open: Vec<u32>,
+}
+
+impl<'b> Parser<'b> {
+    /// Create a parser for `syntax`, which records spans if `spanned` is set.
+    #[inline]
+    #[must_use]
+    fn new(bump: &'b Bump, syntax: Syntax, spanned: bool) -> Parser<'b> {
+        Parser {
+            bump,
+            spans: spanned.then(SpanMap::new),
+            syntax,
+            flags: syntax.flags,
+            depth: 0,
+            nodes: 0,
+            arena_start: bump.allocated_bytes(),
+            errors: None,
+            captures: 0,
+            names: HashMap::new(),
+            open: Vec::new(),
+        }
+    }
+
+    fn pattern(&mut self, input: &mut Input<'_>) -> PResult<Ast<'b>, ParseError> {
+        let mut items = Items::default();
+        let mut start = input.location();
+
+        loop {
+            let ast = self.alternation(input)?;
+            items.push(self, ast, span(start, input));
+
+            // `concat` only stops at a `|` or `)`, and `alternation` consumes every `|`.
+            if input.is_empty() {
+                break;
+            }
+
+            start = input.location();
+            let len = self.token(input).map_or(1, |(_, len)| len);
+            let _ = input.next_slice(len);
+
+            self.error(ParseErrorKind::UnopenedGroup, span(start, input))?;
+            start = input.location();
+        }
+
+        Ok(items.finish(self))
+    }
+
+    fn alternation(&mut self, input: &mut Input<'_>) -> PResult<Ast<'b>, ParseError> {
+        let start = input.location();
+        let first = self.concat(input)?;
+
+        if !matches!(self.token(input), Some((Token::Alt, _))) {
+            return Ok(first);
+        }
+
+        let mut children = vec![first];
+        let mut spans = vec![span(start, input)];
+
+        while self.eat(input, Token::Alt) {
+            let start = input.location();
+            children.push(self.concat(input)?);
+            spans.push(span(start, input));
+        }
+
+        let children = self.children(children, &spans);
+        self.check_size(span(start, input))?;
+
+        Ok(Ast::Alt(Alt { children }))
+    }
+
+    fn concat(&mut self, input: &mut Input<'_>) -> PResult<Ast<'b>, ParseError> {
+        let concat_start = input.location();
+        let mut items = Items::default();
+
+        loop {
+            self.trivia(input);
+
+            if input.is_empty() || matches!(self.token(input), Some((Token::Alt | Token::Close, _)))
+            {
+                break;
+            }
+
+            let start = input.location();
+            let checkpoint = (*input, self.depth, self.flags);
+
+            if let Err(err) = self.concat_item(input, &mut items) {
+                self.recover(err)?;
+
+                // Replace everything up to the end of the alternative with a placeholder.
+                (*input, self.depth, self.flags) = checkpoint;
+                self.skip_alternative(input);
+
+                items.push(self, Ast::Fail, span(start, input));
+            }
+        }
+
+        // Literals and flags are not checked as they are parsed, so check them once they
+        // are in the arena.
+        let ast = items.finish(self);
+        self.check_size(span(concat_start, input))?;
+
+        Ok(ast)
+    }
+
+    /// Parse the next item of a concatenation, along with any repetition of it, into
+    /// `items`.
+    fn concat_item(
+        &mut self,
+        input: &mut Input<'_>,
+        items: &mut Items<'b>,
+    ) -> PResult<(), ParseError> {
+        let start = input.location();
+        let (atom, start) = match self.atom(input, items.is_empty())? {
+            Atom::Quoted(quoted) => match self.quoted(items, quoted, start + 2) {
+                Some(last) => last,
+                None => return Ok(()),
+            },
+            atom => (atom, start),
+        };
+
+        let ast = match atom {
+            // Adjacent characters are merged into a single literal, unless
+            // the character is the operand of a repetition.
+            Atom::Char(c) if !self.repeat_next(input) => {
+                items.push_char(c, span(start, input));
+                return Ok(());
+            }
+            Atom::Char(c) => self.lit(c.as_bytes()),
+            // Flags cannot be repeated, so leave any repetition operator
+            // to be reported as missing its operand.
+            Atom::Ast(ast @ Ast::Flags(_)) => {
+                items.push(self, ast, span(start, input));
+                return Ok(());
+            }
+            Atom::Ast(ast) => ast,
+            Atom::Quoted(_) => unreachable!(),
+        };
+
+        let ast = self.repeat(input, ast, start)?;
+        self.check_size(span(start, input))?;
+        items.push(self, ast, span(start, input));
+
+        Ok(())
+    }
+
+    /// Push all but the last character of `quoted`, which starts at `offset`, to `items`.
+    ///
+    /// Only the last character can be the operand of a repetition, so it is returned
+    /// along with its offset instead.
+    fn quoted<'i>(
+        &self,
+        items: &mut Items<'b>,
+        mut quoted: &[u8],
+        mut offset: usize,
+    ) -> Option<(Atom<'b, 'i>, usize)> {
+        while let Some(c) = Char::decode(quoted) {
+            let len = c.as_bytes().len();
+            quoted = &quoted[len..];
+
+            if quoted.is_empty() {
+                return Some((self.char_atom(c), offset));
+            }
+
+            let span = Span::new(offset, offset + len);
+
+            match self.char_atom(c) {
+                Atom::Char(c) => items.push_char(c, span),
+                Atom::Ast(ast) => items.push(self, ast, span),
+                Atom::Quoted(_) => unreachable!(),
+            }
+
+            offset += len;
+        }
+
+        None
+    }
+
+    /// Parse an optional repetition operator applied to `ast`, which starts at `start`.
+    fn repeat(
+        &mut self,
+        input: &mut Input<'_>,
+        ast: Ast<'b>,
+        start: usize,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let child_span = span(start, input);
+
+        let Some(kind) = self.repeat_kind(input)? else {
+            return Ok(ast);
+        };
+
+        let greediness = self.greediness(input);
+
+        if self.repeat_next(input) {
+            self.trivia(input);
+
+            let start = input.location();
+            return fail(
+                ParseErrorKind::MissingRepeatOperand,
+                Span::new(start, start + 1),
+            );
+        }
+
+        Ok(Ast::Repeat(Repeat {
+            kind,
+            greediness,
+            child: self.alloc(ast, child_span),
+        }))
+    }
+
+    /// Parse the `?` or `+` that makes the repetition before it lazy or possessive.
+    fn greediness(&self, input: &mut Input<'_>) -> Greediness {
+        if self.syntax.dialect != Dialect::Default {
+            return Greediness::Greedy;
+        }
+
+        let greediness = match peek(input) {
+            Some(b'?') => Greediness::Lazy,
+            Some(b'+') if self.syntax.possessive => Greediness::Possessive,
+            _ => return Greediness::Greedy,
+        };
+
+        input.next_token();
+        greediness
+    }
+
+    fn repeat_kind(&mut self, input: &mut Input<'_>) -> PResult<Option<RepeatKind>, ParseError> {
+        if !self.repeat_next(input) {
+            return Ok(None);
+        }
+
+        self.trivia(input);
+
+        let (kind, len) = match self.token(input) {
+            Some((Token::Star, len)) => (RepeatKind::ZERO_OR_MORE, len),
+            Some((Token::Plus, len)) => (RepeatKind::ONE_OR_MORE, len),
+            Some((Token::Question, len)) => (RepeatKind::ZERO_OR_ONE, len),
+            Some((Token::Brace, len)) => return self.counted(input, len).map(Some),
+            _ => return Ok(None),
+        };
+
+        let _ = input.next_slice(len);
+
+        Ok(Some(kind))
+    }
+
+    /// Parse a counted repetition, whose opening brace is `len` bytes long.
+    fn counted(&mut self, input: &mut Input<'_>, len: usize) -> PResult<RepeatKind, ParseError> {
+        let start = input.location();
+        let _ = input.next_slice(len);
+
+        let min = number(input)?;
+        let kind = if opt(b',').parse_next(input)?.is_some() {
+            match (min, number(input)?) {
+                (min, Some(max)) => Some(RepeatKind::Bounded(min.unwrap_or(0), max)),
+                (Some(min), None) => Some(RepeatKind::AtLeast(min)),
+                (None, None) => None,
+            }
+        } else {
+            min.map(RepeatKind::Exact)
+        };
+
+        if !self.eat_close_brace(input) {
+            return match input.next_token() {
+                Some(_) => fail(ParseErrorKind::InvalidRepeat, span(start, input)),
+                None => fail(ParseErrorKind::UnclosedRepeat, span(start, input)),
+            };
+        }
+
+        if let Some(kind) = kind {
+            self.check_repeat(kind, span(start, input))?;
+        }
+
+        match kind {
+            Some(kind) if kind.is_valid() => Ok(kind),
+            Some(kind) => fail(
+                ParseErrorKind::InvalidRepeatBounds(kind),
+                span(start, input),
+            ),
+            None => fail(ParseErrorKind::InvalidRepeat, span(start, input)),
+        }
+    }
+
+    /// Parse a single atom, which is `leading` if it is the first in its concatenation.
+    fn atom<'i>(
+        &mut self,
+        input: &mut Input<'i>,
+        leading: bool,
+    ) -> PResult<Atom<'b, 'i>, ParseError> {
+        let start = input.location();
+
+        match self.token(input) {
+            Some((Token::Open, len)) => return self.group(input, len).map(Atom::Ast),
+            // A leading `*` is a literal in POSIX basic syntax.
+            Some((Token::Star, _)) if leading && self.syntax.dialect == Dialect::PosixBasic => {}
+            Some((token, len)) if token.is_repeat() => {
+                return fail(
+                    ParseErrorKind::MissingRepeatOperand,
+                    Span::new(start, start + len),
+                )
+            }
+            _ => {}
+        }
+
+        match peek(input) {
+            Some(b'[') => self.class(input).map(Atom::Ast),
+            Some(b'.') => {
+                input.next_token();
+
+                if self.flags.contains(Flags::UNICODE) {
+                    let chars: &[CharRange] = if self.flags.contains(Flags::DOT_MATCHES_NEW_LINE) {
+                        &[CharRange::new('\0', char::MAX)]
+                    } else {
+                        &[
+                            CharRange::new('\0', '\t'),
+                            CharRange::new('\x0B', char::MAX),
+                        ]
+                    };
+
+                    Ok(Atom::Ast(self.unicode_class(chars)))
+                } else if self.flags.contains(Flags::DOT_MATCHES_NEW_LINE) {
+                    Ok(Atom::Ast(Ast::Dot))
+                } else {
+                    Ok(Atom::Ast(self.class_of(&[
+                        ByteRange::new(0, b'\n' - 1),
+                        ByteRange::new(b'\n' + 1, u8::MAX),
+                    ])))
+                }
+            }
+            Some(b'\\')
+                if peek_nth(input, 1) == Some(b'Q')
+                    && self.syntax.dialect == Dialect::Default
+                    && self.syntax.escapes.contains(Escapes::QUOTE) =>
+            {
+                Ok(Atom::Quoted(quote(input)))
+            }
+            Some(b'\\') => match self.escape(input)? {
+                Escape::Char(c) => Ok(self.char_atom(c)),
+                Escape::Class(class) => {
+                    let mut ranges = Vec::new();
+                    class.extend_into(&mut ranges);
+
+                    Ok(Atom::Ast(self.class_of(&ranges)))
+                }
+                Escape::Unicode(chars) => Ok(Atom::Ast(self.unicode_class(&chars))),
+                Escape::Look(look) => Ok(Atom::Ast(Ast::Look(look))),
+                Escape::Backref(backref) => Ok(Atom::Ast(Ast::Backref(backref))),
+            },
+            // In POSIX basic syntax, `^` and `$` are only anchors at the start and end of
+            // an expression.
+            Some(b'^') if self.syntax.dialect != Dialect::PosixBasic || leading => {
+                input.next_token();
+
+                Ok(Atom::Ast(Ast::Look(
+                    match (
+                        self.flags.contains(Flags::MULTI_LINE),
+                        self.flags.contains(Flags::CRLF),
+                    ) {
+                        (false, _) => Look::Start,
+                        (true, false) => Look::StartLine,
+                        (true, true) => Look::StartLineCrlf,
+                    },
+                )))
+            }
+            Some(b'$') if self.syntax.dialect != Dialect::PosixBasic || self.trailing(input) => {
+                input.next_token();
+
+                Ok(Atom::Ast(Ast::Look(
+                    match (
+                        self.flags.contains(Flags::MULTI_LINE),
+                        self.flags.contains(Flags::CRLF),
+                    ) {
+                        (false, _) if self.syntax.dollar_before_newline => Look::EndBeforeNewline,
+                        (false, _) => Look::End,
+                        (true, false) => Look::EndLine,
+                        (true, true) => Look::EndLineCrlf,
+                    },
+                )))
+            }
+            _ => Ok(self.char_atom(Char::next(input))),
+        }
+    }
+
+    /// Get the atom matching `c` according to the active flags.
+    ///
+    /// When matching case-insensitively, a character is expanded into a class of those
+    /// equivalent to it under Unicode simple case folding, as in `[Σσς]` for `Σ`. Outside of
+    /// Unicode mode, ASCII characters only fold to their ASCII counterparts.
+    fn char_atom<'i>(&self, c: Char) -> Atom<'b, 'i> {
+        if !self.flags.contains(Flags::CASE_INSENSITIVE) {
+            return Atom::Char(c);
+        }
+
+        // Outside of Unicode mode, only ASCII letters have another case.
+        if !self.flags.contains(Flags::UNICODE) {
+            return match *c.as_bytes() {
+                [byte] if byte.is_ascii_alphabetic() => {
+                    let (upper, lower) = (byte.to_ascii_uppercase(), byte.to_ascii_lowercase());
+                    let ranges = [ByteRange::new(upper, upper), ByteRange::new(lower, lower)];
+
+                    Atom::Ast(self.class_of(&ranges))
+                }
+                _ => Atom::Char(c),
+            };
+        }
+
+        match c.to_char() {
+            Some(ch) if !unicode::simple_case_fold(ch).is_empty() => {
+                let mut chars = vec![CharRange::new(ch, ch)];
+                unicode::add_simple_case_folds(&mut chars);
+                canonicalize_ranges(&mut chars);
+
+                Atom::Ast(self.unicode_class(&chars))
+            }
+            _ => Atom::Char(c),
+        }
+    }
+
+    /// Parse a group, whose opening parenthesis is `len` bytes long.
+    fn group(&mut self, input: &mut Input<'_>, len: usize) -> PResult<Ast<'b>, ParseError> {
+        let start = input.location();
+        let _ = input.next_slice(len);
+
+        if self.syntax.dialect == Dialect::Default {
+            if let Some((kind, prefix)) = lookaround_kind(input) {
+                let _ = input.next_slice(prefix);
+
+                let outer = self.flags;
+                let child = self.group_body(input, start, len)?;
+                self.flags = outer;
+
+                return Ok(Ast::Lookaround(Lookaround { kind, child }));
+            }
+
+            if input.starts_with(b"?>") {
+                let _ = input.next_slice(2);
+
+                let outer = self.flags;
+                let child = self.group_body(input, start, len)?;
+                self.flags = outer;
+
+                return Ok(Ast::Group(Group {
+                    flags: None,
+                    capture: None,
+                    atomic: true,
+                    child,
+                }));
+            }
+        }
+
+        let mut flags = None;
+        let mut name = None;
+
+        if self.syntax.dialect == Dialect::Default && peek(input) == Some(b'?') {
+            input.next_token();
+
+            if let Some(prefix) = [&b"<"[..], b"P<"]
+                .into_iter()
+                .find(|p| input.starts_with(p))
+            {
+                let _ = input.next_slice(prefix.len());
+                name = Some(self.group_name(input, start)?);
+            } else {
+                let set = self.set_flags(input, start)?;
+
+                // `(?flags)` changes the flags until the end of the enclosing group.
+                if opt(b')').parse_next(input)?.is_some() {
+                    if set == SetFlags::default() {
+                        return fail(ParseErrorKind::EmptyFlags, span(start, input));
+                    }
+
+                    self.flags = set.apply(self.flags);
+
+                    return Ok(Ast::Flags(set));
+                }
+
+                input.next_token();
+                flags = Some(set);
+            }
+        }
+
+        // Every group captures, except those starting with `(?:` or another set of flags.
+        let capture = match flags {
+            Some(_) => None,
+            None => {
+                self.captures += 1;
+
+                Some(Capture {
+                    index: self.captures,
+                    name,
+                })
+            }
+        };
+
+        let outer = self.flags;
+
+        if let Some(flags) = flags {
+            self.flags = flags.apply(outer);
+        }
+
+        if let Some(capture) = capture {
+            self.open.push(capture.index);
+        }
+
+        let child = self.group_body(input, start, len);
+        self.flags = outer;
+
+        if capture.is_some() {
+            self.open.pop();
+        }
+
+        let child = child?;
+
+        Ok(Ast::Group(Group {
+            flags,
+            capture,
+            atomic: false,
+            child,
+        }))
+    }
+
+    /// Parse the name of a capture group that starts at `start`, up to and including the
+    /// closing `>`.
+    fn group_name(&mut self, input: &mut Input<'_>, start: usize) -> PResult<&'b str, ParseError> {
+        let name_start = input.location();
+
+        let Some(name) = name(input) else {
+            return fail(ParseErrorKind::InvalidGroupName, span(start, input));
+        };
+
+        let name_span = Span::new(name_start, name_start + name.len());
+        let name: &'b str = self.bump.alloc_str(name);
+
+        // The group is given the next index once its name has been parsed.
+        if self.names.insert(name, self.captures + 1).is_some() {
+            self.error(ParseErrorKind::DuplicateGroupName, name_span)?;
+        }
+
+        Ok(name)
+    }
+
+    /// Parse the rest of a group whose `len` bytes long opening parenthesis is at `start`,
+    /// up to and including its closing parenthesis.
+    fn group_body(
+        &mut self,
+        input: &mut Input<'_>,
+        start: usize,
+        len: usize,
+    ) -> PResult<&'b mut Ast<'b>, ParseError> {
+        self.enter(Span::new(start, start + len))?;
+
+        let child_start = input.location();
+        let child = self.alternation(input)?;
+        let child_span = span(child_start, input);
+
+        self.depth -= 1;
+
+        // A group that is never closed extends to the end of the pattern.
+        if !self.eat(input, Token::Close) {
+            self.error(ParseErrorKind::UnclosedGroup, Span::new(start, start + len))?;
+        }
+
+        Ok(self.alloc(child, child_span))
+    }
+
+    /// Parse the flags of a group that starts at `start`, up to the closing `)` or `:`.
+    fn set_flags(&mut self, input: &mut Input<'_>, start: usize) -> PResult<SetFlags, ParseError> {
+        let mut flags = SetFlags::default();
+        let mut negation = None;
+
+        if !matches!(
+            peek(input),
+            None | Some(b'a'..=b'z' | b'A'..=b'Z' | b'-' | b':' | b')')
+        ) {
+            return fail(ParseErrorKind::UnsupportedGroup, span(start, input));
+        }
+
+        loop {
+            let at = input.location();
+
+            match peek(input) {
+                Some(b')' | b':') => break,
+                Some(b'-') if negation.is_none() => {
+                    input.next_token();
+                    negation = Some(span(at, input));
+                }
+                Some(letter) => {
+                    let c = Char::next(input);
+
+                    let Some(flag) = Flags::from_letter(letter).filter(|_| c.len == 1) else {
+                        return fail(ParseErrorKind::UnrecognizedFlag, span(at, input));
+                    };
+
+                    if flags.enable.union(flags.disable).intersects(flag) {
+                        return fail(ParseErrorKind::RepeatedFlag, span(at, input));
+                    }
+
+                    if negation.is_some() {
+                        flags.disable |= flag;
+                    } else {
+                        flags.enable |= flag;
+                    }
+                }
+                None => return fail(ParseErrorKind::UnclosedGroup, Span::new(start, start + 1)),
+            }
+        }
+
+        match negation {
+            Some(span) if flags.disable.is_empty() => {
+                fail(ParseErrorKind::DanglingFlagNegation, span)
+            }
+            _ => Ok(flags),
+        }
+    }
+
+    fn trivia(&self, input: &mut Input<'_>) {
+        if !self.flags.contains(Flags::IGNORE_WHITESPACE) {
+            return;
+        }
+
+        loop {
+            let _ = input.next_slice(input.iter().take_while(|b| b.is_ascii_whitespace()).count());
+
+            if peek(input) != Some(b'#') {
+                break;
+            }
+
+            let _ = input.next_slice(
+                input
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .unwrap_or(input.eof_offset()),
+            );
+        }
+    }
+
+    /// Check whether a repetition operator comes next, ignoring any trivia.
+    #[must_use]
+    fn repeat_next(&self, input: &Input<'_>) -> bool {
+        let mut input = *input;
+        self.trivia(&mut input);
+
+        self.token(&input)
+            .is_some_and(|(token, _)| token.is_repeat())
+    }
+
+    /// Check whether the byte at the start of `input` is the last of its expression.
+    #[must_use]
+    fn trailing(&self, input: &Input<'_>) -> bool {
+        let mut input = *input;
+        input.next_token();
+
+        input.is_empty() || matches!(self.token(&input), Some((Token::Alt | Token::Close, _)))
+    }
+
+    /// Record `err` if errors are being recovered from, or return it otherwise.
+    ///
+    /// Exceeding the limits on the number of nodes or the size of the arena is never
+    /// recovered from, since the rest of the pattern would only exceed them further.
+    fn recover(&mut self, err: ErrMode<ParseError>) -> PResult<(), ParseError> {
+        match (&mut self.errors, err) {
+            (Some(errors), ErrMode::Cut(err))
+                if !matches!(
+                    err.kind,
+                    ParseErrorKind::NodeLimitExceeded(_) | ParseErrorKind::ArenaLimitExceeded(_)
+                ) =>
+            {
+                errors.push(err);
+                Ok(())
+            }
+            (_, err) => Err(err),
+        }
+    }
+
+    /// Record an error of `kind` at `span` if errors are being recovered from, or return it
+    /// otherwise.
+    fn error(&mut self, kind: ParseErrorKind, span: Span) -> PResult<(), ParseError> {
+        self.recover(ErrMode::Cut(ParseError::new(kind, span)))
+    }
+
+    /// Skip to the next `|` or `)` that is not nested in a group, or to the end of the
+    /// pattern.
+    fn skip_alternative(&self, input: &mut Input<'_>) {
+        let mut depth = 0usize;
+
+        loop {
+            let len = match self.token(input) {
+                Some((Token::Alt | Token::Close, _)) if depth == 0 => return,
+                Some((Token::Open, len)) => {
+                    depth += 1;
+                    len
+                }
+                Some((Token::Close, len)) => {
+                    depth -= 1;
+                    len
+                }
+                Some((_, len)) => len,
+                None => match peek(input) {
+                    None => return,
+                    Some(b'\\') => 1 + Char::decode(&input[1..]).map_or(0, |c| c.as_bytes().len()),
+                    // A bracket class may contain a `|` or `)`, so skip up to its `]` if it
+                    // has one. The first byte is skipped, since it may be a literal `]`.
+                    Some(b'[') => input
+                        .iter()
+                        .skip(2)
+                        .position(|&b| b == b']')
+                        .map_or(1, |len| len + 3),
+                    Some(_) => Char::decode(input).map_or(1, |c| c.as_bytes().len()),
+                },
+            };
+
+            let _ = input.next_slice(len.min(input.eof_offset()));
+        }
+    }
+
+    /// Enter a group or bracket class that opens at `span`.
+    ///
+    /// The caller must decrement `depth` once it has been parsed.
+    fn enter(&mut self, span: Span) -> PResult<(), ParseError> {
+        let limit = self.syntax.limits.nest;
+
+        if self.depth >= limit {
+            return fail(ParseErrorKind::NestLimitExceeded(limit), span);
+        }
+
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Check that the bounds of `kind` are within the repetition limit, blaming `span`
+    /// otherwise.
+    fn check_repeat(&self, kind: RepeatKind, span: Span) -> PResult<(), ParseError> {
+        let limit = self.syntax.limits.repeat;
+
+        if kind.end().unwrap_or(kind.start()) > limit {
+            return fail(ParseErrorKind::RepeatLimitExceeded(limit), span);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the nodes built so far are within the limits, blaming `span` otherwise.
+    fn check_size(&self, span: Span) -> PResult<(), ParseError> {
+        let limits = &self.syntax.limits;
+
+        if self.nodes > limits.nodes {
+            return fail(ParseErrorKind::NodeLimitExceeded(limits.nodes), span);
+        }
+
+        if self.bump.allocated_bytes() - self.arena_start > limits.arena_bytes {
+            return fail(ParseErrorKind::ArenaLimitExceeded(limits.arena_bytes), span);
+        }
+
+        Ok(())
+    }
+
+    /// Move `node` into the arena, recording its span.
+    fn alloc(&mut self, node: Ast<'b>, span: Span) -> &'b mut Ast<'b> {
+        self.nodes += 1;
+        let node = self.bump.alloc(node);
+
+        if let Some(spans) = &mut self.spans {
+            spans.insert(node, span);
+        }
+
+        node
+    }
+
+    /// Move `children` into the arena, recording their spans.
+    fn children(&mut self, children: Vec<Ast<'b>>, spans: &[Span]) -> &'b mut Children<'b> {
+        self.nodes += children.len();
+        let children = NonEmpty::new_mut(self.bump.alloc_slice_fill_iter(children));
+
+        if let Some(map) = &mut self.spans {
+            for (child, &span) in children.iter().zip(spans) {
+                map.insert(child, span);
+            }
+        }
+
+        children
+    }
+
+    #[inline]
+    #[must_use]
+    fn class_of(&self, ranges: &[ByteRange]) -> Ast<'b> {
+        Ast::Class(Class {
+            ranges: NonEmpty::new_mut(self.bump.alloc_slice_copy(ranges)),
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    fn lit(&self, bytes: &[u8]) -> Ast<'b> {
+        Ast::Lit(Lit {
+            bytes: NonEmpty::new_mut(self.bump.alloc_slice_copy(bytes)),
+        })
+    }
+}
+
+/// The result of parsing a single item of a concatenation.
+enum Atom<'b, 'i> {
+    /// A single character, which may be merged with its neighbors.
+    Char(Char),
+    Ast(Ast<'b>),
+    /// The text quoted by `\Q...\E`, all of which is literal.
+    Quoted(&'i [u8]),
+}
+
+/// The children of a concatenation, with adjacent characters merged into literals.
+#[derive(Default)]
+struct Items<'b> {
+    children: Vec<Ast<'b>>,
+    spans: Vec<Span>,
+    lit: Vec<u8>,
+    lit_span: Span,
+}
+
+impl<'b> Items<'b> {
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.children.is_empty() && self.lit.is_empty()
+    }
+
+    fn push_char(&mut self, c: Char, span: Span) {
+        if self.lit.is_empty() {
+            self.lit_span.start = span.start;
+        }
+
+        self.lit.extend_from_slice(c.as_bytes());
+        self.lit_span.end = span.end;
+    }
+
+    fn push(&mut self, parser: &Parser<'b>, ast: Ast<'b>, span: Span) {
+        self.flush(parser);
+        self.children.push(ast);
+        self.spans.push(span);
+    }
+
+    fn flush(&mut self, parser: &Parser<'b>) {
+        if !self.lit.is_empty() {
+            self.children.push(parser.lit(&self.lit));
+            self.spans.push(self.lit_span);
+            self.lit.clear();
+        }
+    }
+
+    fn finish(mut self, parser: &mut Parser<'b>) -> Ast<'b> {
+        self.flush(parser);
+
+        match self.children.len() {
+            0 => Ast::Empty,
+            1 => self.children.pop().unwrap(),
+            _ => Ast::Concat(Concat {
+                children: parser.children(self.children, &self.spans),
+            }),
+        }
+    }
+}
+
+/// The meaning of an escape sequence.
+enum Escape<'b> {
+    /// A single character, as in `\n` or `\x41`.
+    Char(Char),
+    /// A predefined class, as in `\d`.
+    Class(NamedClass),
+    /// The sorted ranges of scalar values with a Unicode property, as in `\p{Greek}`.
+    Unicode(Vec<CharRange>),
+    /// An assertion, as in `\b`.
+    Look(Look),
+    /// A backreference, as in `\1` or `\k<name>`.
+    Backref(Backref<'b>),
+}
+
+/// A single character as it is encoded in a pattern.
+///
+/// This is either a valid UTF-8 sequence or a single byte that is not part of one.
+#[derive(Clone, Copy)]
+struct Char {
+    buf: [u8; 4],
+    len: u8,
+}
+
+impl Char {
+    #[inline]
+    #[must_use]
+    const fn byte(byte: u8) -> Char {
+        Char {
+            buf: [byte, 0, 0, 0],
+            len: 1,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    fn from_char(c: char) -> Char {
+        let mut buf = [0; 4];
+        let len = c.encode_utf8(&mut buf).len();
+
+        Char {
+            buf,
+            len: len as u8,
+        }
+    }
+
+    /// Get the first character of `bytes`, if there is one.
+    #[inline]
+    #[must_use]
+    fn decode(bytes: &[u8]) -> Option<Char> {
+        let chunk = bytes.utf8_chunks().next()?;
+        let len = chunk.valid().chars().next().map_or(1, char::len_utf8);
+
+        let mut buf = [0; 4];
+        buf[..len].copy_from_slice(&bytes[..len]);
+
+        Some(Char {
+            buf,
+            len: len as u8,
+        })
+    }
+
+    /// Consume the next character of `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is empty.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    fn next(input: &mut Input<'_>) -> Char {
+        let c = Char::decode(input).expect("input is not empty");
+        let _ = input.next_slice(c.as_bytes().len());
+
+        c
+    }
+
+    #[inline]
+    #[must_use]
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len as usize]
+    }
+
+    /// Get the scalar value of this character, unless it is a byte that is not part of a
+    /// valid UTF-8 sequence.
+    #[inline]
+    #[must_use]
+    fn to_char(self) -> Option<char> {
+        str::from_utf8(self.as_bytes()).ok()?.chars().next()
+    }
+}
+
+#[inline]
+fn fail<T>(kind: ParseErrorKind, span: Span) -> PResult<T, ParseError> {
+    Err(ErrMode::Cut(ParseError::new(kind, span)))
+}
+
+/// Get the span from `start` to the current location of `input`.
+#[inline]
+#[must_use]
+fn span(start: usize, input: &Input<'_>) -> Span {
+    Span::new(start, input.location())
+}
+
+#[inline]
+#[must_use]
+fn peek(input: &Input<'_>) -> Option<u8> {
+    input.first().copied()
+}
+
+#[inline]
+#[must_use]
+fn peek_nth(input: &Input<'_>, n: usize) -> Option<u8> {
+    input.get(n).copied()
+}
+
+/// Consume a `\\Q...\\E` quotation, returning the quoted text.
+///
+/// A quotation that is never closed extends to the end of the pattern.
+fn quote<'i>(input: &mut Input<'i>) -> &'i [u8] {
+    let _ = input.next_slice(2);
+
+    match input.windows(2).position(|w| w == b"\\E") {
+        Some(len) => {
+            let quoted = input.next_slice(len);
+            let _ = input.next_slice(2);
+            quoted
+        }
+        None => input.finish(),
+    }
+}
+
+/// Get the kind of the lookaround whose prefix, as in `?=` or `?<!`, is at the start of
+/// `input`, along with the length of the prefix.
+#[must_use]
+fn lookaround_kind(input: &Input<'_>) -> Option<(LookaroundKind, usize)> {
+    const PREFIXES: [(&[u8], LookaroundKind); 4] = [
+        (b"?=", LookaroundKind::Ahead),
+        (b"?!", LookaroundKind::NegativeAhead),
+        (b"?<=", LookaroundKind::Behind),
+        (b"?<!", LookaroundKind::NegativeBehind),
+    ];
+
+    PREFIXES
+        .iter()
+        .find(|(prefix, _)| input.starts_with(prefix))
+        .map(|&(prefix, kind)| (kind, prefix.len()))
+}
+
+/// Consume the name of a capture group, which is an identifier, along with the `>` that
+/// closes it.
+fn name<'i>(input: &mut Input<'i>) -> Option<&'i str> {
+    let len = input
+        .iter()
+        .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_')
+        .count();
+
+    let name = input.next_slice(len);
+
+    // Identifiers cannot start with a digit.
+    if name.first().is_none_or(u8::is_ascii_digit) || input.next_token() != Some(b'>') {
+        return None;
+    }
+
+    Some(str::from_utf8(name).expect("name is ASCII"))
+}
+
+/// Parse an optional decimal number.
+fn number(input: &mut Input<'_>) -> PResult<Option<u32>, ParseError> {
+    let start = input.location();
+    let digits: &[u8] = digit0.parse_next(input)?;
+
+    if digits.is_empty() {
+        return Ok(None);
+    }
+
+    match digits.iter().try_fold(0u32, |n, digit| {
+        n.checked_mul(10)?.checked_add(u32::from(digit - b'0'))
+    }) {
+        Some(n) => Ok(Some(n)),
+        None => fail(ParseErrorKind::RepeatBoundOverflow, span(start, input)),
+    }
+}
@@ -0,0 +1,503 @@
+use winnow::{
+    combinator::opt,
+    stream::{Location, Stream},
+    PResult, Parser as _,
+};
+
+use super::{fail, peek, peek_nth, span, Char, Escape, Input, ParseError, ParseErrorKind, Parser};
+use crate::{
+    ast::{
+        canonicalize_ranges, complement_ranges, difference_ranges, intersect_ranges,
+        symmetric_difference_ranges, Ast, ByteRange, CharClass, CharRange, Class, Flags, Look,
+    },
+    unicode,
+    util::{slice::NonEmpty, span::Span},
+};
+
+/// A predefined class of bytes, such as `\d` or `[:alpha:]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct NamedClass {
+    /// The sorted, non-overlapping ranges of the class.
+    ranges: &'static [ByteRange],
+    /// Whether the class matches every byte not in `ranges` instead.
+    negated: bool,
+}
+
+impl NamedClass {
+    /// Get the Perl class for the letter following a `\`, as in `\d` or `\W`.
+    #[must_use]
+    pub(super) fn perl(letter: u8) -> Option<NamedClass> {
+        let ranges = match letter.to_ascii_lowercase() {
+            b'd' => DIGIT,
+            b's' => SPACE,
+            b'w' => WORD,
+            _ => return None,
+        };
+
+        Some(NamedClass {
+            ranges,
+            negated: letter.is_ascii_uppercase(),
+        })
+    }
+
+    /// Get the POSIX class named `name`, as in `[:alpha:]` or `[:^alpha:]`.
+    #[must_use]
+    pub(super) fn posix(name: &[u8]) -> Option<NamedClass> {
+        let (name, negated) = match name.strip_prefix(b"^") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+
+        let ranges = match name {
+            b"alnum" => ALNUM,
+            b"alpha" => ALPHA,
+            b"ascii" => ASCII,
+            b"blank" => BLANK,
+            b"cntrl" => CNTRL,
+            b"digit" => DIGIT,
+            b"graph" => GRAPH,
+            b"lower" => LOWER,
+            b"print" => PRINT,
+            b"punct" => PUNCT,
+            b"space" => SPACE,
+            b"upper" => UPPER,
+            b"word" => WORD,
+            b"xdigit" => XDIGIT,
+            _ => return None,
+        };
+
+        Some(NamedClass { ranges, negated })
+    }
+
+    /// Append the ranges matched by this class to `ranges`.
+    pub(super) fn extend_into(self, ranges: &mut Vec<ByteRange>) {
+        if self.negated {
+            ranges.extend(complement_ranges(self.ranges));
+        } else {
+            ranges.extend_from_slice(self.ranges);
+        }
+    }
+
+    /// Append the ranges of scalar values matched by this class to `chars`, in which a
+    /// negated class matches every scalar value that is not one of its ASCII characters.
+    pub(super) fn extend_chars_into(self, chars: &mut Vec<CharRange>) {
+        let ranges: Vec<_> = self
+            .ranges
+            .iter()
+            .map(|&range| CharRange::from(range))
+            .collect();
+
+        if self.negated {
+            chars.extend(complement_ranges(&ranges));
+        } else {
+            chars.extend(ranges);
+        }
+    }
+}
+
+const ALNUM: &[ByteRange] = &[
+    ByteRange::new(b'0', b'9'),
+    ByteRange::new(b'A', b'Z'),
+    ByteRange::new(b'a', b'z'),
+];
+const ALPHA: &[ByteRange] = &[ByteRange::new(b'A', b'Z'), ByteRange::new(b'a', b'z')];
+const ASCII: &[ByteRange] = &[ByteRange::new(0x00, 0x7F)];
+const BLANK: &[ByteRange] = &[ByteRange::new(b'\t', b'\t'), ByteRange::new(b' ', b' ')];
+const CNTRL: &[ByteRange] = &[ByteRange::new(0x00, 0x1F), ByteRange::new(0x7F, 0x7F)];
+const DIGIT: &[ByteRange] = &[ByteRange::new(b'0', b'9')];
+const GRAPH: &[ByteRange] = &[ByteRange::new(b'!', b'~')];
+const LOWER: &[ByteRange] = &[ByteRange::new(b'a', b'z')];
+const PRINT: &[ByteRange] = &[ByteRange::new(b' ', b'~')];
+const PUNCT: &[ByteRange] = &[
+    ByteRange::new(b'!', b'/'),
+    ByteRange::new(b':', b'@'),
+    ByteRange::new(b'[', b'`'),
+    ByteRange::new(b'{', b'~'),
+];
+const SPACE: &[ByteRange] = &[ByteRange::new(b'\t', b'\r'), ByteRange::new(b' ', b' ')];
+const UPPER: &[ByteRange] = &[ByteRange::new(b'A', b'Z')];
+const WORD: &[ByteRange] = &[
+    ByteRange::new(b'0', b'9'),
+    ByteRange::new(b'A', b'Z'),
+    ByteRange::new(b'_', b'_'),
+    ByteRange::new(b'a', b'z'),
+];
+const XDIGIT: &[ByteRange] = &[
+    ByteRange::new(b'0', b'9'),
+    ByteRange::new(b'A', b'F'),
+    ByteRange::new(b'a', b'f'),
+];
+
+/// A single item of a bracket class.
+enum Item {
+    /// A single character, which is the scalar value of the same number as its byte
+    /// outside of Unicode mode.
+    Char(char),
+    Class(NamedClass),
+    Set(ClassSet),
+}
+
+/// The set matched by a bracket class or one of its operands, which is always canonical.
+enum ClassSet {
+    Bytes(Vec<ByteRange>),
+    /// A set of scalar values, for a class with a Unicode property in it.
+    ///
+    /// Any bytes combined with such a set are taken as the scalar values of the same number.
+    Unicode(Vec<CharRange>),
+}
+
+impl ClassSet {
+    fn into_unicode(self) -> Vec<CharRange> {
+        match self {
+            ClassSet::Bytes(ranges) => {
+                let mut chars: Vec<_> =
+                    ranges.iter().map(|&range| CharRange::from(range)).collect();
+                canonicalize_ranges(&mut chars);
+
+                chars
+            }
+            ClassSet::Unicode(chars) => chars,
+        }
+    }
+
+    fn union(self, other: ClassSet) -> ClassSet {
+        match (self, other) {
+            (ClassSet::Bytes(mut a), ClassSet::Bytes(b)) => {
+                a.extend(b);
+                canonicalize_ranges(&mut a);
+
+                ClassSet::Bytes(a)
+            }
+            (a, b) => {
+                let mut chars = a.into_unicode();
+                chars.extend(b.into_unicode());
+                canonicalize_ranges(&mut chars);
+
+                ClassSet::Unicode(chars)
+            }
+        }
+    }
+
+    fn apply(self, op: SetOp, other: ClassSet) -> ClassSet {
+        match (self, other) {
+            (ClassSet::Bytes(a), ClassSet::Bytes(b)) => ClassSet::Bytes(match op {
+                SetOp::Intersection => intersect_ranges(&a, &b),
+                SetOp::Difference => difference_ranges(&a, &b),
+                SetOp::SymmetricDifference => symmetric_difference_ranges(&a, &b),
+            }),
+            (a, b) => {
+                let (a, b) = (a.into_unicode(), b.into_unicode());
+
+                ClassSet::Unicode(match op {
+                    SetOp::Intersection => intersect_ranges(&a, &b),
+                    SetOp::Difference => difference_ranges(&a, &b),
+                    SetOp::SymmetricDifference => symmetric_difference_ranges(&a, &b),
+                })
+            }
+        }
+    }
+
+    fn complement(self) -> ClassSet {
+        match self {
+            ClassSet::Bytes(ranges) => ClassSet::Bytes(complement_ranges(&ranges)),
+            ClassSet::Unicode(chars) => ClassSet::Unicode(complement_ranges(&chars)),
+        }
+    }
+}
+
+/// An operator between the operands of a bracket class.
+#[derive(Clone, Copy)]
+enum SetOp {
+    /// `&&`
+    Intersection,
+    /// `--`
+    Difference,
+    /// `~~`
+    SymmetricDifference,
+}
+
+impl<'b> Parser<'b> {
+    /// Get the set operator at the start of `input`, if there is one.
+    ///
+    /// There are none unless [`Syntax::class_set_operations`](super::Syntax::class_set_operations)
+    /// is set.
+    fn set_op(&self, input: &Input<'_>) -> Option<SetOp> {
+        if !self.syntax.class_set_operations {
+            return None;
+        }
+
+        match input.get(..2)? {
+            b"&&" => Some(SetOp::Intersection),
+            b"--" => Some(SetOp::Difference),
+            b"~~" => Some(SetOp::SymmetricDifference),
+            _ => None,
+        }
+    }
+
+    pub(super) fn class(&mut self, input: &mut Input<'_>) -> PResult<Ast<'b>, ParseError> {
+        match self.class_set(input)? {
+            ClassSet::Bytes(ranges) => {
+                match NonEmpty::try_new_mut(self.bump.alloc_slice_copy(&ranges)) {
+                    Ok(ranges) => Ok(Ast::Class(Class { ranges })),
+                    Err(_) => Ok(Ast::Fail),
+                }
+            }
+            ClassSet::Unicode(chars) => Ok(self.unicode_class(&chars)),
+        }
+    }
+
+    /// Parse a bracket class, which may be nested in another one.
+    ///
+    /// Operators are applied from left to right, and bind less tightly than the union of
+    /// the items between them, so `[a-z&&[^aeiou]]` matches every consonant.
+    fn class_set(&mut self, input: &mut Input<'_>) -> PResult<ClassSet, ParseError> {
+        let start = input.location();
+        input.next_token();
+
+        self.enter(Span::new(start, start + 1))?;
+
+        let negated = opt(b'^').parse_next(input)?.is_some();
+        let mut set = self.class_operand(input, start, true)?;
+
+        while let Some(op) = self.set_op(input) {
+            let _ = input.next_slice(2);
+            set = set.apply(op, self.class_operand(input, start, false)?);
+        }
+
+        // `class_operand` only stops at an operator, a `]` or the end of the pattern.
+        if input.next_token().is_none() {
+            return fail(ParseErrorKind::UnclosedClass, Span::new(start, start + 1));
+        }
+
+        self.depth -= 1;
+
+        Ok(if negated { set.complement() } else { set })
+    }
+
+    /// Parse the union of the items between the operators of the class starting at `start`,
+    /// where `first` is whether this is the first of them.
+    fn class_operand(
+        &mut self,
+        input: &mut Input<'_>,
+        start: usize,
+        first: bool,
+    ) -> PResult<ClassSet, ParseError> {
+        let unicode = self.flags.contains(Flags::UNICODE);
+        let mut chars = Vec::new();
+        let mut set = ClassSet::Bytes(Vec::new());
+        let mut is_empty = true;
+
+        loop {
+            match peek(input) {
+                Some(b']') if !(first && is_empty && self.syntax.literal_bracket) => break,
+                Some(_) if self.set_op(input).is_some() => break,
+                Some(_) => is_empty = false,
+                None => return fail(ParseErrorKind::UnclosedClass, Span::new(start, start + 1)),
+            }
+
+            let item = input.location();
+            let lo = match self.class_item(input)? {
+                Item::Char(c) => c,
+                Item::Class(class) if unicode => {
+                    class.extend_chars_into(&mut chars);
+                    continue;
+                }
+                Item::Class(class) => {
+                    let mut ranges = Vec::new();
+                    class.extend_into(&mut ranges);
+
+                    chars.extend(ranges.iter().map(|&range| CharRange::from(range)));
+                    continue;
+                }
+                Item::Set(other) => {
+                    set = set.union(other);
+                    continue;
+                }
+            };
+
+            // A `-` right before the closing `]` or a `--` operator is a literal.
+            let hi = if peek(input) == Some(b'-')
+                && !matches!(peek_nth(input, 1), Some(b']') | None)
+                && !(peek_nth(input, 1) == Some(b'-') && self.syntax.class_set_operations)
+            {
+                input.next_token();
+
+                match self.class_item(input)? {
+                    Item::Char(c) => c,
+                    Item::Class(_) | Item::Set(_) => {
+                        return fail(ParseErrorKind::InvalidClassRange, span(item, input))
+                    }
+                }
+            } else {
+                lo
+            };
+
+            if lo > hi {
+                return fail(ParseErrorKind::InvalidClassRange, span(item, input));
+            }
+
+            chars.push(CharRange::new(lo, hi));
+        }
+
+        if is_empty && !self.syntax.empty_class {
+            let end = input.location() + usize::from(peek(input).is_some());
+            return fail(ParseErrorKind::EmptyClass, Span::new(start, end));
+        }
+
+        self.fold_case(&mut chars);
+
+        if unicode {
+            canonicalize_ranges(&mut chars);
+            return Ok(set.union(ClassSet::Unicode(chars)));
+        }
+
+        // Outside of Unicode mode, every character stands for a single byte.
+        let mut ranges: Vec<_> = chars
+            .iter()
+            .map(|range| ByteRange::new(range.start as u8, range.end as u8))
+            .collect();
+        canonicalize_ranges(&mut ranges);
+
+        Ok(set.union(ClassSet::Bytes(ranges)))
+    }
+
+    /// Add the scalar values that match those in `chars` in case-insensitive mode, if it is
+    /// active, after which they need to be canonicalized.
+    pub(super) fn fold_case(&self, chars: &mut Vec<CharRange>) {
+        if !self.flags.contains(Flags::CASE_INSENSITIVE) {
+            return;
+        }
+
+        if self.flags.contains(Flags::UNICODE) {
+            unicode::add_simple_case_folds(chars);
+        } else {
+            add_ascii_case(chars);
+        }
+    }
+
+    /// Build a class matching the UTF-8 encoding of any scalar value.
+    pub(super) fn any_char(&self) -> Ast<'b> {
+        self.unicode_class(&[CharRange::new('\0', char::MAX)])
+    }
+
+    /// Build a class matching the UTF-8 encoding of the scalar values in `chars`, which
+    /// must be canonical, or [`Ast::Fail`] if there are none.
+    pub(super) fn unicode_class(&self, chars: &[CharRange]) -> Ast<'b> {
+        match NonEmpty::try_new_mut(self.bump.alloc_slice_copy(chars)) {
+            Ok(ranges) => Ast::CharClass(CharClass { ranges }),
+            Err(_) => Ast::Fail,
+        }
+    }
+
+    fn class_item(&mut self, input: &mut Input<'_>) -> PResult<Item, ParseError> {
+        let start = input.location();
+
+        let c = match peek(input) {
+            Some(b'[') => {
+                if let Some(len) = posix_len(input) {
+                    let name = &input.next_slice(len + 4)[2..len + 2];
+
+                    return match NamedClass::posix(name) {
+                        Some(class) => Ok(Item::Class(class)),
+                        None => fail(ParseErrorKind::UnrecognizedClass, span(start, input)),
+                    };
+                }
+
+                if self.syntax.nested_classes {
+                    return self.class_set(input).map(Item::Set);
+                }
+
+                // Only equivalence classes and collating symbols of a single byte are
+                // supported, which stand for just that byte. Other dialects take the `[` as a
+                // literal.
+                match collating_len(input).filter(|_| self.syntax.dialect.is_posix()) {
+                    Some(len) => {
+                        return match input.next_slice(len + 4)[2..len + 2] {
+                            [byte] if byte.is_ascii() || !self.flags.contains(Flags::UNICODE) => {
+                                Ok(Item::Char(char::from(byte)))
+                            }
+                            _ => fail(ParseErrorKind::UnrecognizedClass, span(start, input)),
+                        };
+                    }
+                    None => Char::next(input),
+                }
+            }
+            // Escapes are not recognized in POSIX bracket expressions.
+            Some(b'\\') if self.syntax.dialect.is_posix() => Char::next(input),
+            Some(b'\\') => match self.escape(input)? {
+                Escape::Char(c) => c,
+                Escape::Class(class) => return Ok(Item::Class(class)),
+                Escape::Unicode(chars) => return Ok(Item::Set(ClassSet::Unicode(chars))),
+                // `\b` is a backspace in a bracket class.
+                Escape::Look(Look::WordBoundary | Look::WordBoundaryUnicode) => Char::byte(0x08),
+                Escape::Look(_) => return fail(ParseErrorKind::LookInClass, span(start, input)),
+                Escape::Backref(_) => {
+                    return fail(ParseErrorKind::BackrefInClass, span(start, input))
+                }
+            },
+            _ => Char::next(input),
+        };
+
+        if self.flags.contains(Flags::UNICODE) {
+            return match c.to_char() {
+                Some(c) => Ok(Item::Char(c)),
+                None => fail(ParseErrorKind::NonAsciiClass, span(start, input)),
+            };
+        }
+
+        match c.as_bytes() {
+            &[byte] => Ok(Item::Char(char::from(byte))),
+            _ => fail(ParseErrorKind::NonAsciiClass, span(start, input)),
+        }
+    }
+}
+
+/// Get the length of the name of the POSIX class at the start of `input`, as in `[:alpha:]`.
+///
+/// A `[:` that is not closed by a `:]` is not a POSIX class.
+pub(super) fn posix_len(input: &Input<'_>) -> Option<usize> {
+    let rest = input.strip_prefix(b"[:")?;
+    let len = rest
+        .iter()
+        .position(|&b| !(b.is_ascii_alphabetic() || b == b'^'))?;
+
+    rest[len..].starts_with(b":]").then_some(len)
+}
+
+/// Get the length of the contents of the equivalence class or collating symbol at the
+/// start of `input`, as in `[=a=]` or `[.a.]`.
+fn collating_len(input: &Input<'_>) -> Option<usize> {
+    let delimiter = match input.get(..2)? {
+        b"[=" => b'=',
+        b"[." => b'.',
+        _ => return None,
+    };
+
+    input[2..]
+        .windows(2)
+        .position(|window| window == [delimiter, b']'])
+}
+
+/// Add the other case of every ASCII letter in `ranges`.
+fn add_ascii_case(ranges: &mut Vec<CharRange>) {
+    const CASE: u8 = b'a' - b'A';
+
+    for i in 0..ranges.len() {
+        let CharRange { start, end } = ranges[i];
+        let end = end.min('\x7F') as u8;
+
+        if !start.is_ascii() {
+            continue;
+        }
+
+        let (lo, hi) = ((start as u8).max(b'A'), end.min(b'Z'));
+        if lo <= hi {
+            ranges.push(CharRange::new(char::from(lo + CASE), char::from(hi + CASE)));
+        }
+
+        let (lo, hi) = ((start as u8).max(b'a'), end.min(b'z'));
+        if lo <= hi {
+            ranges.push(CharRange::new(char::from(lo - CASE), char::from(hi - CASE)));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+use winnow::stream::Stream;
+
+use super::{peek, peek_nth, Input, Parser};
+
+/// The syntax that a pattern is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// The default syntax, which is similar to that of Perl.
+    #[default]
+    Default,
+    /// POSIX basic regular expressions, as used by `grep` and `sed`.
+    ///
+    /// The GNU extensions `\+`, `\?` and `\|` are supported, as are the shorthand classes
+    /// `\w`, `\W`, `\s` and `\S`.
+    PosixBasic,
+    /// POSIX extended regular expressions, as used by `grep -E` and `awk`.
+    ///
+    /// The shorthand classes `\w`, `\W`, `\s` and `\S` are supported.
+    PosixExtended,
+}
+
+impl Dialect {
+    /// Check whether this is one of the POSIX dialects.
+    #[inline]
+    #[must_use]
+    pub const fn is_posix(self) -> bool {
+        matches!(self, Dialect::PosixBasic | Dialect::PosixExtended)
+    }
+
+    /// Get how a match is chosen for patterns in this dialect.
+    #[inline]
+    #[must_use]
+    pub const fn match_kind(self) -> MatchKind {
+        match self {
+            Dialect::Default => MatchKind::LeftmostFirst,
+            Dialect::PosixBasic | Dialect::PosixExtended => MatchKind::LeftmostLongest,
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Dialect::Default => "default",
+            Dialect::PosixBasic => "POSIX basic",
+            Dialect::PosixExtended => "POSIX extended",
+        })
+    }
+}
+
+/// How a match is chosen among those that start at the leftmost position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MatchKind {
+    /// The first match found when alternatives and repetitions are tried in order of
+    /// preference, as in Perl.
+    #[default]
+    LeftmostFirst,
+    /// The longest match, as required by POSIX.
+    LeftmostLongest,
+}
+
+/// An operator, which is spelled differently depending on the dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Token {
+    /// `|`, or `\|` in POSIX basic syntax.
+    Alt,
+    /// `(`, or `\(` in POSIX basic syntax.
+    Open,
+    /// `)`, or `\)` in POSIX basic syntax.
+    Close,
+    /// `*`.
+    Star,
+    /// `+`, or `\+` in POSIX basic syntax.
+    Plus,
+    /// `?`, or `\?` in POSIX basic syntax.
+    Question,
+    /// `{`, or `\{` in POSIX basic syntax.
+    Brace,
+}
+
+impl Token {
+    #[inline]
+    #[must_use]
+    pub(super) const fn is_repeat(self) -> bool {
+        matches!(
+            self,
+            Token::Star | Token::Plus | Token::Question | Token::Brace
+        )
+    }
+}
+
+impl Parser<'_> {
+    /// Get the operator at the start of `input` along with its length, if there is one.
+    #[must_use]
+    pub(super) fn token(&self, input: &Input<'_>) -> Option<(Token, usize)> {
+        let (byte, len) = match (self.syntax.dialect, peek(input)?) {
+            (Dialect::PosixBasic, b'*') => return Some((Token::Star, 1)),
+            (Dialect::PosixBasic, b'\\') => match peek_nth(input, 1)? {
+                b'*' => return None,
+                byte => (byte, 2),
+            },
+            (Dialect::PosixBasic, _) => return None,
+            (_, byte) => (byte, 1),
+        };
+
+        let token = match byte {
+            b'|' => Token::Alt,
+            b'(' => Token::Open,
+            b')' => Token::Close,
+            b'*' => Token::Star,
+            b'+' => Token::Plus,
+            b'?' => Token::Question,
+            b'{' if self.syntax.literal_brace && !is_counted(input) => return None,
+            b'{' => Token::Brace,
+            _ => return None,
+        };
+
+        Some((token, len))
+    }
+
+    /// Consume `token` if it comes next.
+    pub(super) fn eat(&self, input: &mut Input<'_>, token: Token) -> bool {
+        match self.token(input) {
+            Some((next, len)) if next == token => {
+                let _ = input.next_slice(len);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume the `}` that closes a counted repetition, if it comes next.
+    pub(super) fn eat_close_brace(&self, input: &mut Input<'_>) -> bool {
+        let close: &[u8] = match self.syntax.dialect {
+            Dialect::PosixBasic => b"\\}",
+            _ => b"}",
+        };
+
+        if !input.starts_with(close) {
+            return false;
+        }
+
+        let _ = input.next_slice(close.len());
+        true
+    }
+}
+
+/// Check whether `input` starts with a well-formed counted repetition, as in `{2}`, `{2,}`
+/// or `{2,3}`.
+#[must_use]
+fn is_counted(input: &Input<'_>) -> bool {
+    let digits = |bytes: &[u8]| bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let rest = &input[1..];
+    let min = digits(rest);
+
+    let rest = match rest[min..].strip_prefix(b",") {
+        Some(rest) => &rest[digits(rest)..],
+        None => &rest[min..],
+    };
+
+    min > 0 && rest.first() == Some(&b'}')
+}
@@ -0,0 +1,300 @@
+use std::{
+    error::Error,
+    fmt::{self, Write},
+};
+
+use winnow::{
+    error::{ErrorKind, ParserError},
+    stream::{Location, Stream},
+};
+
+use super::Dialect;
+use crate::{
+    ast::RepeatKind,
+    util::{span::Span, BytesExt},
+};
+
+/// An error produced when a pattern could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// The bytes of the pattern that caused the error.
+    pub span: Span,
+}
+
+impl ParseError {
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: ParseErrorKind, span: Span) -> ParseError {
+        ParseError { kind, span }
+    }
+
+    /// Get a displayable diagnostic that underlines the span of this error in `pattern`.
+    ///
+    /// `pattern` must be the pattern this error was produced for.
+    #[inline]
+    #[must_use]
+    pub const fn diagnostic<'a>(&'a self, pattern: &'a [u8]) -> Diagnostic<'a> {
+        Diagnostic {
+            error: self,
+            pattern,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:?}", self.kind, self.span)
+    }
+}
+
+impl Error for ParseError {}
+
+/// The reason a pattern could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A token that cannot appear at this position.
+    Unexpected,
+    /// A `(` without a matching `)`.
+    UnclosedGroup,
+    /// A `)` without a matching `(`.
+    UnopenedGroup,
+    /// A group using `(?` syntax, which is not supported.
+    UnsupportedGroup,
+    /// A capture group name that is empty, is not an identifier or is missing its `>`.
+    InvalidGroupName,
+    /// A capture group name used by more than one group.
+    DuplicateGroupName,
+    /// A flag that does not exist.
+    UnrecognizedFlag,
+    /// A flag that appears more than once in the same group.
+    RepeatedFlag,
+    /// A `-` in a flag group that is not followed by any flags.
+    DanglingFlagNegation,
+    /// A flag group without any flags, as in `(?)`.
+    EmptyFlags,
+    /// A `[` without a matching `]`.
+    UnclosedClass,
+    /// A bracket class without any items.
+    EmptyClass,
+    /// A class range whose start is greater than its end.
+    InvalidClassRange,
+    /// A non-ASCII character inside of a bracket class outside of Unicode mode, or a byte
+    /// that is not part of a valid UTF-8 sequence inside of one in Unicode mode or in a glob.
+    NonAsciiClass,
+    /// A POSIX class with an unknown name, as in `[[:foo:]]`.
+    UnrecognizedClass,
+    /// A `\p` escape with an unknown Unicode property, as in `\p{Foo}`.
+    UnrecognizedProperty,
+    /// An assertion inside of a bracket class, as in `[\A]`.
+    LookInClass,
+    /// A backreference inside of a bracket class, as in `[\1]`.
+    BackrefInClass,
+    /// A backreference to a capture group that does not exist, or that is not closed before
+    /// it.
+    UndefinedGroup,
+    /// A `\` at the end of the pattern.
+    DanglingEscape,
+    /// A `\` followed by a character that cannot be escaped.
+    UnrecognizedEscape,
+    /// An escape that is valid in the default syntax, but not in this dialect.
+    NotInDialect(Dialect),
+    /// An escape that has been disabled by the [`Syntax`](super::Syntax), as in `\e` in
+    /// ECMAScript syntax.
+    DisabledEscape,
+    /// A malformed numeric or control character escape.
+    InvalidEscape,
+    /// A numeric escape for a single byte whose value exceeds `0xFF`.
+    EscapeOutOfRange,
+    /// A `\u` escape that is not a Unicode scalar value.
+    InvalidCodePoint,
+    /// A repetition operator without anything to repeat.
+    MissingRepeatOperand,
+    /// A `{` without a matching `}`.
+    UnclosedRepeat,
+    /// A counted repetition that is malformed.
+    InvalidRepeat,
+    /// A counted repetition whose minimum is greater than its maximum.
+    InvalidRepeatBounds(RepeatKind),
+    /// A counted repetition bound that does not fit in a `u32`.
+    RepeatBoundOverflow,
+    /// A `{` in a glob without a matching `}`.
+    UnclosedBraces,
+    /// A quoted string or prose description in a grammar without a closing delimiter.
+    UnclosedString,
+    /// A comment in a grammar without a closing `*)`.
+    UnclosedComment,
+    /// A reference to a rule that is not defined by the grammar.
+    UndefinedRule,
+    /// A rule that is defined more than once.
+    DuplicateRule,
+    /// A rule that refers to itself, which cannot be expanded.
+    RecursiveRule,
+    /// A prose description, as in ABNF `<text>` or EBNF `? text ?`, which cannot be
+    /// expanded.
+    UnsupportedProse,
+    /// An EBNF exception whose operands do not only match single bytes.
+    UnsupportedException,
+    /// Groups or bracket classes nested more deeply than [`Limits::nest`](super::Limits::nest).
+    NestLimitExceeded(u32),
+    /// More nodes than [`Limits::nodes`](super::Limits::nodes) were built.
+    NodeLimitExceeded(usize),
+    /// The arena grew by more than [`Limits::arena_bytes`](super::Limits::arena_bytes).
+    ArenaLimitExceeded(usize),
+    /// A counted repetition bound greater than [`Limits::repeat`](super::Limits::repeat).
+    RepeatLimitExceeded(u32),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Unexpected => f.write_str("unexpected token"),
+            ParseErrorKind::UnclosedGroup => f.write_str("unclosed group"),
+            ParseErrorKind::UnopenedGroup => f.write_str("unopened group"),
+            ParseErrorKind::UnsupportedGroup => f.write_str("unsupported group syntax"),
+            ParseErrorKind::InvalidGroupName => f.write_str("invalid capture group name"),
+            ParseErrorKind::DuplicateGroupName => f.write_str("duplicate capture group name"),
+            ParseErrorKind::UnrecognizedFlag => f.write_str("unrecognized flag"),
+            ParseErrorKind::RepeatedFlag => f.write_str("repeated flag"),
+            ParseErrorKind::DanglingFlagNegation => f.write_str("flag negation without any flags"),
+            ParseErrorKind::EmptyFlags => f.write_str("empty flag group"),
+            ParseErrorKind::UnclosedClass => f.write_str("unclosed character class"),
+            ParseErrorKind::EmptyClass => f.write_str("empty character class"),
+            ParseErrorKind::InvalidClassRange => f.write_str("invalid character class range"),
+            ParseErrorKind::NonAsciiClass => f.write_str("non-ASCII character in character class"),
+            ParseErrorKind::UnrecognizedClass => f.write_str("unrecognized POSIX class"),
+            ParseErrorKind::UnrecognizedProperty => f.write_str("unrecognized Unicode property"),
+            ParseErrorKind::LookInClass => f.write_str("assertion in character class"),
+            ParseErrorKind::BackrefInClass => f.write_str("backreference in character class"),
+            ParseErrorKind::UndefinedGroup => {
+                f.write_str("backreference to an undefined capture group")
+            }
+            ParseErrorKind::DanglingEscape => f.write_str("dangling escape"),
+            ParseErrorKind::UnrecognizedEscape => f.write_str("unrecognized escape sequence"),
+            ParseErrorKind::NotInDialect(dialect) => {
+                write!(f, "escape sequence not supported in {dialect} syntax")
+            }
+            ParseErrorKind::DisabledEscape => {
+                f.write_str("escape sequence is not enabled in this syntax")
+            }
+            ParseErrorKind::InvalidEscape => f.write_str("invalid escape sequence"),
+            ParseErrorKind::EscapeOutOfRange => f.write_str("escaped byte exceeds 0xFF"),
+            ParseErrorKind::InvalidCodePoint => f.write_str("escape is not a Unicode scalar value"),
+            ParseErrorKind::MissingRepeatOperand => {
+                f.write_str("repetition operator missing expression")
+            }
+            ParseErrorKind::UnclosedRepeat => f.write_str("unclosed counted repetition"),
+            ParseErrorKind::InvalidRepeat => f.write_str("invalid counted repetition"),
+            ParseErrorKind::InvalidRepeatBounds(kind) => write!(
+                f,
+                "invalid repetition bounds: minimum {} is greater than maximum {}",
+                kind.start(),
+                kind.end().unwrap_or(u32::MAX),
+            ),
+            ParseErrorKind::RepeatBoundOverflow => {
+                write!(f, "repetition bound exceeds {}", u32::MAX)
+            }
+            ParseErrorKind::UnclosedBraces => f.write_str("unclosed brace alternation"),
+            ParseErrorKind::UnclosedString => f.write_str("unclosed string"),
+            ParseErrorKind::UnclosedComment => f.write_str("unclosed comment"),
+            ParseErrorKind::UndefinedRule => f.write_str("undefined rule"),
+            ParseErrorKind::DuplicateRule => f.write_str("rule is already defined"),
+            ParseErrorKind::RecursiveRule => f.write_str("recursive rule cannot be expanded"),
+            ParseErrorKind::UnsupportedProse => f.write_str("prose descriptions are not supported"),
+            ParseErrorKind::UnsupportedException => {
+                f.write_str("exception operands must only match single bytes")
+            }
+            ParseErrorKind::NestLimitExceeded(limit) => {
+                write!(f, "nesting depth exceeds the limit of {limit}")
+            }
+            ParseErrorKind::NodeLimitExceeded(limit) => {
+                write!(f, "pattern exceeds the limit of {limit} nodes")
+            }
+            ParseErrorKind::ArenaLimitExceeded(limit) => {
+                write!(f, "pattern exceeds the memory limit of {limit} bytes")
+            }
+            ParseErrorKind::RepeatLimitExceeded(limit) => {
+                write!(f, "repetition bound exceeds the limit of {limit}")
+            }
+        }
+    }
+}
+
+impl<I: Stream + Location> ParserError<I> for ParseError {
+    #[inline]
+    fn from_error_kind(input: &I, _kind: ErrorKind) -> Self {
+        ParseError::new(ParseErrorKind::Unexpected, Span::at(input.location()))
+    }
+
+    #[inline]
+    fn append(
+        self,
+        _input: &I,
+        _token_start: &<I as Stream>::Checkpoint,
+        _kind: ErrorKind,
+    ) -> Self {
+        self
+    }
+}
+
+/// A [`ParseError`] rendered against its pattern, with the offending bytes underlined.
+///
+/// ```text
+/// error: unclosed group
+///   |
+/// 1 | ab(cd
+///   |   ^
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Diagnostic<'a> {
+    error: &'a ParseError,
+    pattern: &'a [u8],
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Span { start, end } = self.error.span;
+        let start = start.min(self.pattern.len());
+        let end = end.clamp(start, self.pattern.len());
+
+        // Only the line containing the start of the span is shown.
+        let line_start = self.pattern[..start]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_end = self.pattern[start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(self.pattern.len(), |i| start + i);
+        let line_number = self.pattern[..line_start]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+            + 1;
+
+        let line = &self.pattern[line_start..line_end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let before = &line[..(start - line_start).min(line.len())];
+        let within = &line[before.len()..(end - line_start).min(line.len())];
+
+        let gutter = line_number.to_string();
+        let pad = " ".repeat(gutter.len());
+
+        writeln!(f, "error: {}", self.error.kind)?;
+        writeln!(f, "{pad} |")?;
+        writeln!(f, "{gutter} | {}", line.byte_str())?;
+        write!(f, "{pad} | ")?;
+
+        // Mirror tabs so the carets line up no matter how they are rendered.
+        for c in before.byte_str().to_string().chars() {
+            f.write_char(if c == '\t' { '\t' } else { ' ' })?;
+        }
+
+        for _ in 0..within.byte_str().to_string().chars().count().max(1) {
+            f.write_char('^')?;
+        }
+
+        Ok(())
+    }
+}
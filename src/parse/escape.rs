@@ -0,0 +1,319 @@
+use std::str;
+
+use winnow::{
+    stream::{Location, Stream},
+    PResult,
+};
+
+use super::{
+    fail, name, peek, span, Char, Dialect, Escape, Escapes, Input, NamedClass, ParseError,
+    ParseErrorKind, Parser,
+};
+use crate::{
+    ast::{canonicalize_ranges, complement_ranges, Backref, BackrefCase, CharRange, Flags, Look},
+    unicode,
+    util::span::Span,
+};
+
+impl<'b> Parser<'b> {
+    /// Parse an escape sequence that stands for a single character or a class.
+    pub(super) fn escape(&mut self, input: &mut Input<'_>) -> PResult<Escape<'b>, ParseError> {
+        let start = input.location();
+        input.next_token();
+
+        let Some(byte) = peek(input) else {
+            return fail(ParseErrorKind::DanglingEscape, span(start, input));
+        };
+
+        // Consume the entire escaped character, so that errors cover all of it.
+        let _ = Char::next(input);
+
+        if self.syntax.dialect.is_posix() {
+            return self.posix_escape(input, start, byte);
+        }
+
+        if let Some(class) = self.perl(byte) {
+            return Ok(class);
+        }
+
+        let escapes = match byte {
+            b'a' => Escapes::BELL,
+            b'e' => Escapes::ESCAPE,
+            b'x' => Escapes::HEX,
+            b'u' => Escapes::UNICODE,
+            b'o' => Escapes::OCTAL,
+            b'0' if matches!(peek(input), Some(b'0'..=b'7')) => Escapes::OCTAL,
+            b'c' => Escapes::CONTROL,
+            b'Q' => Escapes::QUOTE,
+            b'p' | b'P' => Escapes::PROPERTY,
+            b'A' | b'z' => Escapes::ANCHOR,
+            b'Z' => Escapes::ANCHOR_NEWLINE,
+            _ => Escapes::EMPTY,
+        };
+
+        if !self.syntax.escapes.contains(escapes) {
+            return fail(ParseErrorKind::DisabledEscape, span(start, input));
+        }
+
+        let unicode = self.flags.contains(Flags::UNICODE);
+
+        let look = match byte {
+            b'A' => Some(Look::Start),
+            b'z' => Some(Look::End),
+            b'Z' => Some(Look::EndBeforeNewline),
+            b'b' if unicode => Some(Look::WordBoundaryUnicode),
+            b'b' => Some(Look::WordBoundary),
+            b'B' if unicode => Some(Look::NotWordBoundaryUnicode),
+            b'B' => Some(Look::NotWordBoundary),
+            _ => None,
+        };
+
+        if let Some(look) = look {
+            return Ok(Escape::Look(look));
+        }
+
+        match byte {
+            b'1'..=b'9' => {
+                let digits = input.iter().take_while(|b| b.is_ascii_digit()).count();
+                let index = [byte]
+                    .iter()
+                    .chain(input.next_slice(digits))
+                    .fold(0u32, |n, digit| {
+                        n.saturating_mul(10).saturating_add(u32::from(digit - b'0'))
+                    });
+
+                return self.backref(index, None, span(start, input));
+            }
+            b'k' if peek(input) == Some(b'<') => {
+                input.next_token();
+
+                let Some(name) = name(input) else {
+                    return fail(ParseErrorKind::InvalidGroupName, span(start, input));
+                };
+
+                let index = self.names.get(name).copied().unwrap_or(u32::MAX);
+                return self.backref(index, Some(name), span(start, input));
+            }
+            _ => {}
+        }
+
+        let byte = match byte {
+            b'a' => 0x07,
+            b'e' => 0x1B,
+            b'f' => 0x0C,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => 0x0B,
+            b'x' => return self.numeric_escape(input, start, 16, 2).map(Escape::Char),
+            b'o' => return self.numeric_escape(input, start, 8, 0).map(Escape::Char),
+            b'0' => {
+                // `\0` is followed by at most two more octal digits.
+                let digits = input
+                    .iter()
+                    .take(2)
+                    .take_while(|b| matches!(b, b'0'..=b'7'))
+                    .count();
+
+                input
+                    .next_slice(digits)
+                    .iter()
+                    .fold(0, |n, digit| n * 8 + (digit - b'0'))
+            }
+            b'c' => match input.next_token() {
+                Some(b'?') => 0x7F,
+                Some(letter @ (b'@'..=b'_' | b'a'..=b'z')) => letter.to_ascii_uppercase() ^ 0x40,
+                _ => return fail(ParseErrorKind::InvalidEscape, span(start, input)),
+            },
+            b'u' => {
+                let value = number_escape(input, start, 16, 4)?;
+
+                return match char::from_u32(value) {
+                    Some(c) => Ok(Escape::Char(Char::from_char(c))),
+                    None => fail(ParseErrorKind::InvalidCodePoint, span(start, input)),
+                };
+            }
+            b'p' | b'P' => return self.property(input, start, byte == b'P'),
+            byte if byte.is_ascii_punctuation() || byte.is_ascii_whitespace() => byte,
+            _ => return fail(ParseErrorKind::UnrecognizedEscape, span(start, input)),
+        };
+
+        Ok(Escape::Char(Char::byte(byte)))
+    }
+
+    /// Interpret the escaped `byte` in one of the POSIX dialects, which only support
+    /// escaping punctuation and a few shorthand classes.
+    fn posix_escape(
+        &self,
+        input: &mut Input<'_>,
+        start: usize,
+        byte: u8,
+    ) -> PResult<Escape<'b>, ParseError> {
+        match byte {
+            b'w' | b'W' | b's' | b'S' => Ok(self.perl(byte).expect("byte is a Perl class")),
+            b'1'..=b'9' if self.syntax.dialect == Dialect::PosixBasic => {
+                self.backref(u32::from(byte - b'0'), None, span(start, input))
+            }
+            b'<' => Ok(Escape::Look(Look::WordStart)),
+            b'>' => Ok(Escape::Look(Look::WordEnd)),
+            b'b' => Ok(Escape::Look(Look::WordBoundary)),
+            b'B' => Ok(Escape::Look(Look::NotWordBoundary)),
+            b'`' => Ok(Escape::Look(Look::Start)),
+            b'\'' => Ok(Escape::Look(Look::End)),
+            byte if byte.is_ascii_punctuation() => Ok(Escape::Char(Char::byte(byte))),
+            _ => fail(
+                ParseErrorKind::NotInDialect(self.syntax.dialect),
+                span(start, input),
+            ),
+        }
+    }
+
+    /// Get the Perl class for the escaped `letter`, which matches scalar values instead of
+    /// bytes in Unicode mode.
+    #[must_use]
+    fn perl(&self, letter: u8) -> Option<Escape<'b>> {
+        if self.flags.contains(Flags::UNICODE) {
+            unicode::perl(letter).map(Escape::Unicode)
+        } else {
+            NamedClass::perl(letter).map(Escape::Class)
+        }
+    }
+
+    /// Parse the Unicode property of a `\\p` or `\\P` escape, as in `\\pL` or `\\p{Greek}`.
+    fn property(
+        &mut self,
+        input: &mut Input<'_>,
+        start: usize,
+        negated: bool,
+    ) -> PResult<Escape<'b>, ParseError> {
+        let name = match peek(input) {
+            Some(b'{') => {
+                input.next_token();
+
+                let Some(len) = input.iter().position(|&b| b == b'}') else {
+                    return fail(ParseErrorKind::InvalidEscape, span(start, input));
+                };
+
+                let name = input.next_slice(len);
+                input.next_token();
+
+                name
+            }
+            Some(byte) if byte.is_ascii_alphabetic() => input.next_slice(1),
+            _ => return fail(ParseErrorKind::InvalidEscape, span(start, input)),
+        };
+
+        let (name, negated) = match name.strip_prefix(b"^") {
+            Some(name) => (name, !negated),
+            None => (name, negated),
+        };
+
+        let Some(ranges) = str::from_utf8(name).ok().and_then(unicode::property) else {
+            return fail(ParseErrorKind::UnrecognizedProperty, span(start, input));
+        };
+
+        let mut ranges: Vec<_> = ranges.iter().map(|&range| CharRange::from(range)).collect();
+
+        // The property is case folded before it is negated, so that in case-insensitive mode
+        // `\P{Lu}` matches neither `a` nor `A`.
+        self.fold_case(&mut ranges);
+        canonicalize_ranges(&mut ranges);
+
+        Ok(Escape::Unicode(if negated {
+            complement_ranges(&ranges)
+        } else {
+            ranges
+        }))
+    }
+
+    /// Build a backreference to the capture group with `index`, which must have been closed
+    /// before it, or fail with an error covering `span`.
+    fn backref(
+        &self,
+        index: u32,
+        name: Option<&str>,
+        span: Span,
+    ) -> PResult<Escape<'b>, ParseError> {
+        if index == 0 || index > self.captures || self.open.contains(&index) {
+            return fail(ParseErrorKind::UndefinedGroup, span);
+        }
+
+        let case = if !self.flags.contains(Flags::CASE_INSENSITIVE) {
+            BackrefCase::Sensitive
+        } else if self.flags.contains(Flags::UNICODE) {
+            BackrefCase::Simple
+        } else {
+            BackrefCase::Ascii
+        };
+
+        Ok(Escape::Backref(Backref {
+            index,
+            name: name.map(|name| &*self.bump.alloc_str(name)),
+            case,
+        }))
+    }
+
+    /// Parse the digits of an escape for a single byte, or for a scalar value in Unicode
+    /// mode.
+    fn numeric_escape(
+        &mut self,
+        input: &mut Input<'_>,
+        start: usize,
+        radix: u32,
+        len: usize,
+    ) -> PResult<Char, ParseError> {
+        let value = number_escape(input, start, radix, len)?;
+
+        if self.flags.contains(Flags::UNICODE) {
+            return match char::from_u32(value) {
+                Some(c) => Ok(Char::from_char(c)),
+                None => fail(ParseErrorKind::InvalidCodePoint, span(start, input)),
+            };
+        }
+
+        match u8::try_from(value) {
+            Ok(byte) => Ok(Char::byte(byte)),
+            Err(_) => fail(ParseErrorKind::EscapeOutOfRange, span(start, input)),
+        }
+    }
+}
+
+/// Parse the digits of a numeric escape that starts at `start`.
+///
+/// The digits are either enclosed in braces or, if `len` is nonzero, exactly `len` digits
+/// without them. Values too large for a `u32` saturate.
+fn number_escape(
+    input: &mut Input<'_>,
+    start: usize,
+    radix: u32,
+    len: usize,
+) -> PResult<u32, ParseError> {
+    let braced = peek(input) == Some(b'{');
+
+    if braced {
+        input.next_token();
+    } else if len == 0 {
+        return fail(ParseErrorKind::InvalidEscape, span(start, input));
+    }
+
+    let count = input
+        .iter()
+        .take(if braced { usize::MAX } else { len })
+        .take_while(|&&b| char::from(b).is_digit(radix))
+        .count();
+
+    let digits = input.next_slice(count);
+
+    if digits.is_empty() || (!braced && digits.len() != len) {
+        return fail(ParseErrorKind::InvalidEscape, span(start, input));
+    }
+
+    if braced && input.next_token() != Some(b'}') {
+        return fail(ParseErrorKind::InvalidEscape, span(start, input));
+    }
+
+    Ok(digits.iter().fold(0u32, |n, &digit| {
+        let digit = char::from(digit).to_digit(radix).unwrap();
+        n.saturating_mul(radix).saturating_add(digit)
+    }))
+}
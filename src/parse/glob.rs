@@ -0,0 +1,429 @@
+use bumpalo::Bump;
+use winnow::{
+    error::ErrMode,
+    stream::{Located, Location, Stream},
+    PResult,
+};
+
+use super::{
+    class::posix_len, fail, peek, peek_nth, span, Char, Input, Items, NamedClass, ParseError,
+    ParseErrorKind, Parser, Syntax,
+};
+use crate::{
+    ast::{
+        canonicalize_ranges, complement_ranges, difference_ranges, Alt, Ast, ByteRange, CharRange,
+        Concat, Greediness, Repeat, RepeatKind, SpanMap,
+    },
+    util::span::Span,
+};
+
+/// The path separator, which only `**` can match.
+const SEPARATOR: CharRange = CharRange::new('/', '/');
+
+/// Every byte except the path separator.
+const NOT_SEPARATOR: &[ByteRange] = &[ByteRange::new(0, b'/' - 1), ByteRange::new(b'/' + 1, 0xFF)];
+
+/// Every scalar value except the path separator.
+const NOT_SEPARATOR_CHARS: &[CharRange] =
+    &[CharRange::new('\0', '.'), CharRange::new('0', char::MAX)];
+
+/// Parse the shell glob `pattern` into an [`Ast`] allocated in `bump`, which matches
+/// exactly the paths that the glob matches.
+///
+/// `*` matches any number of bytes and `?` any single UTF-8 encoded scalar value, but
+/// neither matches a `/`.
+/// A `**` that makes up an entire component matches any number of components instead,
+/// so `src/**/*.rs` matches both `src/main.rs` and `src/parse/glob.rs`. Bracket
+/// expressions such as `[a-z]`, `[!0-9]` and `[[:alpha:]]` match a single scalar value but
+/// never a `/`, and braces
+/// match any of their comma-separated alternatives, as in `*.{rs,toml}`. A `[` or `{`
+/// without a matching `]` or `}` is a literal. Any character may be escaped with `\`.
+pub fn parse_glob<'b>(pattern: &[u8], bump: &'b Bump) -> Result<Ast<'b>, ParseError> {
+    let mut input = Located::new(pattern);
+
+    Parser::new(bump, Syntax::DEFAULT, false)
+        .glob(&mut input, true)
+        .map_err(|err| err.into_inner().expect("input is never partial"))
+}
+
+/// Parse the shell glob `pattern` into an [`Ast`] allocated in `bump`, along with the
+/// span of every node.
+///
+/// See [`parse_glob`] for the syntax of globs.
+pub fn parse_glob_spanned<'b>(
+    pattern: &[u8],
+    bump: &'b Bump,
+) -> Result<(&'b mut Ast<'b>, SpanMap), ParseError> {
+    let mut input = Located::new(pattern);
+    let mut parser = Parser::new(bump, Syntax::DEFAULT, true);
+
+    let ast = parser
+        .glob(&mut input, true)
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+    let ast = parser.alloc(ast, Span::new(0, pattern.len()));
+
+    Ok((ast, parser.spans.unwrap_or_default()))
+}
+
+/// A pattern from a line of a `.gitignore` file.
+#[derive(Debug)]
+pub struct Gitignore<'b> {
+    /// Matches the paths that the pattern applies to, relative to the directory containing
+    /// the file.
+    pub ast: Ast<'b>,
+    /// Whether the line starts with `!`, so that the paths it matches are included again.
+    pub negated: bool,
+    /// Whether the line ends with `/`, so that it only applies to directories.
+    pub dir_only: bool,
+}
+
+/// Parse a `line` of a `.gitignore` file, without its line terminator, into a [`Gitignore`]
+/// allocated in `bump`.
+///
+/// Blank lines and comments starting with `#` have no pattern, and trailing spaces are
+/// ignored unless they are escaped. A pattern that contains a `/` before its end is
+/// anchored to the directory containing the file, while any other pattern matches at any
+/// depth. Otherwise, patterns are globs as in [`parse_glob`], except that braces are not
+/// special.
+pub fn parse_gitignore<'b>(
+    line: &[u8],
+    bump: &'b Bump,
+) -> Result<Option<Gitignore<'b>>, ParseError> {
+    let mut end = line.len();
+    while end > 0 && line[end - 1] == b' ' && !is_escaped(line, end - 1) {
+        end -= 1;
+    }
+
+    let line = &line[..end];
+    let (negated, mut start) = match line.first() {
+        None | Some(b'#') => return Ok(None),
+        Some(b'!') => (true, 1),
+        Some(_) => (false, 0),
+    };
+
+    let dir_only = line.len() > start && line[end - 1] == b'/' && !is_escaped(line, end - 1);
+    if dir_only {
+        end -= 1;
+    }
+
+    let anchored = line[start..end].contains(&b'/');
+    if line.get(start) == Some(&b'/') {
+        start += 1;
+    }
+
+    if start >= end {
+        return Ok(None);
+    }
+
+    let mut input = Located::new(&line[..end]);
+    let _ = input.next_slice(start);
+
+    let mut parser = Parser::new(bump, Syntax::DEFAULT, false);
+
+    let mut ast = parser
+        .glob(&mut input, false)
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+
+    // An unanchored pattern behaves as though it starts with `**/`.
+    if !anchored {
+        let span = Span::new(start, end);
+        let dirs = parser.any_components(span);
+
+        ast = Ast::Concat(Concat {
+            children: parser.children(vec![dirs, ast], &[Span::at(start), span]),
+        });
+    }
+
+    Ok(Some(Gitignore {
+        ast,
+        negated,
+        dir_only,
+    }))
+}
+
+impl<'b> Parser<'b> {
+    /// Parse a glob, in which braces are alternations if `braces` is set.
+    fn glob(&mut self, input: &mut Input<'_>, braces: bool) -> PResult<Ast<'b>, ParseError> {
+        // Outside of braces, every `,` and `}` is a literal, so all of `input` is consumed.
+        self.glob_concat(input, braces, false, true)
+    }
+
+    /// Parse a sequence of glob items, stopping at a `,` or `}` if it is `nested` in braces.
+    ///
+    /// `component` is set if the sequence starts at the beginning of a path component.
+    fn glob_concat(
+        &mut self,
+        input: &mut Input<'_>,
+        braces: bool,
+        nested: bool,
+        mut component: bool,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let mut items = Items::default();
+
+        loop {
+            let start = input.location();
+
+            let ast = match peek(input) {
+                None => break,
+                Some(b',' | b'}') if nested => break,
+                Some(b'*') => {
+                    let stars = input.iter().take_while(|&&b| b == b'*').count();
+                    let _ = input.next_slice(stars);
+
+                    let ends_component = match peek(input) {
+                        None | Some(b'/') => true,
+                        Some(b',' | b'}') => nested,
+                        Some(_) => false,
+                    };
+
+                    if stars > 1 && component && ends_component {
+                        if peek(input) == Some(b'/') {
+                            input.next_token();
+
+                            // `**/` is followed by the start of another component.
+                            let ast = self.any_components(span(start, input));
+                            items.push(self, ast, span(start, input));
+                            continue;
+                        }
+
+                        let any = self.alloc(Ast::Dot, span(start, input));
+
+                        Ast::Repeat(Repeat {
+                            kind: RepeatKind::ZERO_OR_MORE,
+                            greediness: Greediness::Greedy,
+                            child: any,
+                        })
+                    } else {
+                        let any = self.class_of(NOT_SEPARATOR);
+
+                        Ast::Repeat(Repeat {
+                            kind: RepeatKind::ZERO_OR_MORE,
+                            greediness: Greediness::Greedy,
+                            child: self.alloc(any, span(start, input)),
+                        })
+                    }
+                }
+                Some(b'?') => {
+                    input.next_token();
+                    self.unicode_class(NOT_SEPARATOR_CHARS)
+                }
+                Some(b'[') => {
+                    let mut ahead = *input;
+
+                    match self.glob_class(&mut ahead) {
+                        // A `[` without a matching `]` is a literal.
+                        Err(ErrMode::Cut(err)) if err.kind == ParseErrorKind::UnclosedClass => {
+                            input.next_token();
+                            component = false;
+                            items.push_char(Char::byte(b'['), span(start, input));
+                            continue;
+                        }
+                        ast => {
+                            *input = ahead;
+                            ast?
+                        }
+                    }
+                }
+                Some(b'{') if braces => {
+                    let depth = self.depth;
+                    let mut ahead = *input;
+
+                    match self.glob_braces(&mut ahead, component) {
+                        // A `{` without a matching `}` is a literal. Any braces around it are
+                        // not closed either, so only the outermost fall back to a literal.
+                        Err(ErrMode::Cut(err))
+                            if !nested && err.kind == ParseErrorKind::UnclosedBraces =>
+                        {
+                            self.depth = depth;
+                            input.next_token();
+                            component = false;
+                            items.push_char(Char::byte(b'{'), span(start, input));
+                            continue;
+                        }
+                        ast => {
+                            *input = ahead;
+                            ast?
+                        }
+                    }
+                }
+                Some(b) => {
+                    if b == b'\\' {
+                        input.next_token();
+
+                        if input.is_empty() {
+                            return fail(ParseErrorKind::DanglingEscape, span(start, input));
+                        }
+                    }
+
+                    let c = Char::next(input);
+                    component = c.as_bytes() == b"/";
+                    items.push_char(c, span(start, input));
+                    continue;
+                }
+            };
+
+            component = false;
+            self.check_size(span(start, input))?;
+            items.push(self, ast, span(start, input));
+        }
+
+        Ok(items.finish(self))
+    }
+
+    /// Build a node matching any number of path components, each followed by a `/`, as for
+    /// `**/`.
+    fn any_components(&mut self, span: Span) -> Ast<'b> {
+        let any = self.alloc(Ast::Dot, span);
+        let any = Ast::Repeat(Repeat {
+            kind: RepeatKind::ZERO_OR_MORE,
+            greediness: Greediness::Greedy,
+            child: any,
+        });
+        let separator = self.lit(b"/");
+
+        let dirs = Ast::Concat(Concat {
+            children: self.children(vec![any, separator], &[span, span]),
+        });
+
+        Ast::Repeat(Repeat {
+            kind: RepeatKind::ZERO_OR_ONE,
+            greediness: Greediness::Greedy,
+            child: self.alloc(dirs, span),
+        })
+    }
+
+    /// Parse a brace alternation, as in `{a,b}`, whose alternatives start at the beginning
+    /// of a path component if `component` is set.
+    fn glob_braces(
+        &mut self,
+        input: &mut Input<'_>,
+        component: bool,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let start = input.location();
+        let mut children = Vec::new();
+        let mut spans = Vec::new();
+
+        self.enter(Span::new(start, start + 1))?;
+
+        loop {
+            // Consume the `{` or `,` before the alternative.
+            input.next_token();
+
+            let alternative = input.location();
+            children.push(self.glob_concat(input, true, true, component)?);
+            spans.push(span(alternative, input));
+
+            match peek(input) {
+                Some(b',') => {}
+                Some(_) => break,
+                None => return fail(ParseErrorKind::UnclosedBraces, Span::new(start, start + 1)),
+            }
+        }
+
+        input.next_token();
+        self.depth -= 1;
+
+        match children.len() {
+            1 => Ok(children.pop().unwrap()),
+            _ => Ok(Ast::Alt(Alt {
+                children: self.children(children, &spans),
+            })),
+        }
+    }
+
+    /// Parse a bracket expression, as in `[a-z]` or `[!0-9]`, which matches a single scalar
+    /// value but never a `/`.
+    fn glob_class(&mut self, input: &mut Input<'_>) -> PResult<Ast<'b>, ParseError> {
+        let start = input.location();
+        input.next_token();
+
+        let negated = matches!(peek(input), Some(b'!' | b'^'));
+        if negated {
+            input.next_token();
+        }
+
+        let mut chars = Vec::new();
+        let mut first = true;
+
+        loop {
+            let item = input.location();
+
+            // A `]` is a literal if it is the first item.
+            if peek(input) == Some(b']') && !first {
+                input.next_token();
+                break;
+            }
+
+            first = false;
+
+            if let Some(len) = posix_len(input) {
+                let name = &input.next_slice(len + 4)[2..len + 2];
+
+                match NamedClass::posix(name) {
+                    Some(class) => class.extend_chars_into(&mut chars),
+                    None => return fail(ParseErrorKind::UnrecognizedClass, span(item, input)),
+                }
+
+                continue;
+            }
+
+            let lo = class_char(input, start)?;
+            let hi =
+                if peek(input) == Some(b'-') && !matches!(peek_nth(input, 1), Some(b']') | None) {
+                    input.next_token();
+                    class_char(input, start)?
+                } else {
+                    lo
+                };
+
+            if lo > hi {
+                return fail(ParseErrorKind::InvalidClassRange, span(item, input));
+            }
+
+            chars.push(CharRange::new(lo, hi));
+        }
+
+        canonicalize_ranges(&mut chars);
+
+        // Like `?`, a negated class matches any scalar value that is not one of its members.
+        if negated {
+            chars = complement_ranges(&chars);
+        }
+
+        let chars = difference_ranges(&chars, &[SEPARATOR]);
+
+        Ok(self.unicode_class(&chars))
+    }
+}
+
+/// Parse a possibly escaped character in the bracket expression starting at `start`.
+///
+/// A byte that is not part of a valid UTF-8 sequence is not a character.
+fn class_char(input: &mut Input<'_>, start: usize) -> PResult<char, ParseError> {
+    let item = input.location();
+
+    if peek(input) == Some(b'\\') {
+        input.next_token();
+    }
+
+    if input.is_empty() {
+        return fail(ParseErrorKind::UnclosedClass, Span::new(start, start + 1));
+    }
+
+    match Char::next(input).to_char() {
+        Some(c) => Ok(c),
+        None => fail(ParseErrorKind::NonAsciiClass, span(item, input)),
+    }
+}
+
+/// Check whether the byte at `index` in `line` is escaped by an odd number of `\`.
+#[must_use]
+fn is_escaped(line: &[u8], index: usize) -> bool {
+    line[..index]
+        .iter()
+        .rev()
+        .take_while(|&&b| b == b'\\')
+        .count()
+        % 2
+        == 1
+}
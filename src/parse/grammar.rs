@@ -0,0 +1,501 @@
+mod abnf;
+mod ebnf;
+
+pub use abnf::*;
+pub use ebnf::*;
+
+use bumpalo::Bump;
+use winnow::{stream::Location, PResult};
+
+use super::{fail, Input, Limits, ParseError, ParseErrorKind, Parser, Syntax};
+use crate::{
+    ast::{
+        canonicalize_ranges, difference_ranges, Alt, Ast, ByteRange, CharRange, Concat, Greediness,
+        Group, Repeat, RepeatKind,
+    },
+    util::span::Span,
+};
+
+/// The named rules of an ABNF or EBNF grammar.
+///
+/// Rules are kept as they were written, and only expanded into an [`Ast`] on request, so
+/// that rules which cannot be expanded do not prevent the others from being used.
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    rules: Vec<Rule>,
+    /// Whether rule names are compared regardless of ASCII case, as in ABNF.
+    case_insensitive: bool,
+}
+
+impl Grammar {
+    /// Get the names of the rules defined by the grammar, in the order they were defined.
+    ///
+    /// Predefined rules, such as the ABNF core rules, are not included unless they were
+    /// redefined.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .filter(|rule| !rule.core)
+            .map(|rule| rule.name.as_str())
+    }
+
+    /// Expand the rule `name` into an [`Ast`] allocated in `bump`, with every rule it refers
+    /// to expanded in its place.
+    ///
+    /// Returns `None` if there is no rule named `name`. A rule that refers to itself,
+    /// directly or through other rules, cannot be expanded since its language may not be
+    /// regular.
+    pub fn expand<'b>(&self, name: &str, bump: &'b Bump) -> Option<Result<Ast<'b>, ParseError>> {
+        self.expand_with(name, Limits::DEFAULT, bump)
+    }
+
+    /// Expand the rule `name` into an [`Ast`] allocated in `bump` as [`Grammar::expand`]
+    /// does, within `limits` rather than the default ones.
+    ///
+    /// Each rule reference counts as a level of nesting.
+    pub fn expand_with<'b>(
+        &self,
+        name: &str,
+        limits: Limits,
+        bump: &'b Bump,
+    ) -> Option<Result<Ast<'b>, ParseError>> {
+        let index = self.find(name)?;
+
+        let syntax = Syntax {
+            limits,
+            ..Syntax::DEFAULT
+        };
+        let mut parser = Parser::new(bump, syntax, false);
+
+        Some(
+            parser
+                .rule(self, index, &mut Vec::new())
+                .map_err(|err| err.into_inner().expect("input is never partial")),
+        )
+    }
+
+    /// Get the index of the rule named `name`.
+    fn find(&self, name: &str) -> Option<usize> {
+        self.rules.iter().position(|rule| {
+            if self.case_insensitive {
+                rule.name.eq_ignore_ascii_case(name)
+            } else {
+                rule.name == name
+            }
+        })
+    }
+
+    /// Add the rule `name`, defined as `expr`, or add `expr` as another alternative of an
+    /// existing rule if `incremental` is set.
+    fn define(
+        &mut self,
+        name: String,
+        span: Span,
+        expr: Expr,
+        incremental: bool,
+    ) -> PResult<(), ParseError> {
+        let index = self.find(&name);
+
+        match index.map(|index| &mut self.rules[index]) {
+            // Extending a predefined rule makes it part of the grammar.
+            Some(rule) if incremental => {
+                rule.core = false;
+                let first = std::mem::replace(&mut rule.expr, Expr::Alt(Vec::new()));
+
+                rule.expr = match first {
+                    Expr::Alt(mut alternatives) => {
+                        alternatives.push(expr);
+                        Expr::Alt(alternatives)
+                    }
+                    first => Expr::Alt(vec![first, expr]),
+                };
+            }
+            _ if incremental => return fail(ParseErrorKind::UndefinedRule, span),
+            Some(rule) if !rule.core => return fail(ParseErrorKind::DuplicateRule, span),
+            // A grammar may redefine a predefined rule.
+            _ => {
+                if let Some(index) = index {
+                    self.rules.remove(index);
+                }
+
+                self.rules.push(Rule {
+                    name,
+                    expr,
+                    core: false,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    name: String,
+    expr: Expr,
+    /// Whether the rule is predefined rather than part of the grammar.
+    core: bool,
+}
+
+/// The definition of a rule, before the rules it refers to are expanded.
+#[derive(Debug, Clone)]
+enum Expr {
+    /// Bytes that are matched exactly.
+    Lit(Vec<u8>),
+    /// Bytes that are matched regardless of ASCII case.
+    Text(Vec<u8>),
+    /// A set of bytes.
+    Bytes(Vec<ByteRange>),
+    /// A set of scalar values, which are matched by their UTF-8 encodings.
+    Chars(Vec<CharRange>),
+    Concat(Vec<Expr>),
+    Alt(Vec<Expr>),
+    /// A repetition of an expression, at a span.
+    Repeat(RepeatKind, Box<Expr>, Span),
+    Group(Box<Expr>),
+    /// A reference to the rule with a name, at a span.
+    Rule(String, Span),
+    /// Anything matched by the first expression but not the second, at a span.
+    Except(Box<Expr>, Box<Expr>, Span),
+    /// A prose description at a span, which cannot be expanded.
+    Prose(Span),
+}
+
+impl Expr {
+    #[inline]
+    #[must_use]
+    const fn empty() -> Expr {
+        Expr::Concat(Vec::new())
+    }
+
+    #[inline]
+    #[must_use]
+    fn bytes(ranges: &[(u8, u8)]) -> Expr {
+        Expr::Bytes(
+            ranges
+                .iter()
+                .map(|&(start, end)| ByteRange::new(start, end))
+                .collect(),
+        )
+    }
+
+    /// Collect `exprs` into a single expression with `f`, unless there is only one.
+    #[must_use]
+    fn collect(mut exprs: Vec<Expr>, f: fn(Vec<Expr>) -> Expr) -> Expr {
+        match exprs.len() {
+            1 => exprs.pop().unwrap(),
+            _ => f(exprs),
+        }
+    }
+}
+
+/// Get the core rules of ABNF, as defined by RFC 5234.
+#[must_use]
+fn core_rules() -> Vec<Rule> {
+    let wsp = || Expr::bytes(&[(b'\t', b'\t'), (b' ', b' ')]);
+
+    let rules = [
+        ("ALPHA", Expr::bytes(&[(b'A', b'Z'), (b'a', b'z')])),
+        ("BIT", Expr::bytes(&[(b'0', b'1')])),
+        ("CHAR", Expr::bytes(&[(0x01, 0x7F)])),
+        ("CR", Expr::Lit(b"\r".to_vec())),
+        ("CRLF", Expr::Lit(b"\r\n".to_vec())),
+        ("CTL", Expr::bytes(&[(0x00, 0x1F), (0x7F, 0x7F)])),
+        ("DIGIT", Expr::bytes(&[(b'0', b'9')])),
+        ("DQUOTE", Expr::Lit(b"\"".to_vec())),
+        (
+            "HEXDIG",
+            Expr::bytes(&[(b'0', b'9'), (b'A', b'F'), (b'a', b'f')]),
+        ),
+        ("HTAB", Expr::Lit(b"\t".to_vec())),
+        ("LF", Expr::Lit(b"\n".to_vec())),
+        (
+            "LWSP",
+            Expr::Repeat(
+                RepeatKind::ZERO_OR_MORE,
+                Box::new(Expr::Alt(vec![
+                    wsp(),
+                    Expr::Concat(vec![Expr::Lit(b"\r\n".to_vec()), wsp()]),
+                ])),
+                Span::default(),
+            ),
+        ),
+        ("OCTET", Expr::bytes(&[(0x00, 0xFF)])),
+        ("SP", Expr::Lit(b" ".to_vec())),
+        ("VCHAR", Expr::bytes(&[(0x21, 0x7E)])),
+        ("WSP", wsp()),
+    ];
+
+    rules
+        .into_iter()
+        .map(|(name, expr)| Rule {
+            name: name.to_owned(),
+            expr,
+            core: true,
+        })
+        .collect()
+}
+
+impl<'b> Parser<'b> {
+    /// Expand the rule at `index` of `grammar`, where `stack` holds the rules that are
+    /// already being expanded.
+    fn rule(
+        &mut self,
+        grammar: &Grammar,
+        index: usize,
+        stack: &mut Vec<usize>,
+    ) -> PResult<Ast<'b>, ParseError> {
+        stack.push(index);
+        let ast = self.expr(grammar, &grammar.rules[index].expr, stack)?;
+        stack.pop();
+
+        Ok(ast)
+    }
+
+    /// Resolve the rule called `name`, referred to at `span`.
+    fn reference(
+        &self,
+        grammar: &Grammar,
+        name: &str,
+        span: Span,
+        stack: &[usize],
+    ) -> PResult<usize, ParseError> {
+        match grammar.find(name) {
+            Some(index) if stack.contains(&index) => fail(ParseErrorKind::RecursiveRule, span),
+            Some(index) => Ok(index),
+            None => fail(ParseErrorKind::UndefinedRule, span),
+        }
+    }
+
+    fn expr(
+        &mut self,
+        grammar: &Grammar,
+        expr: &Expr,
+        stack: &mut Vec<usize>,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let ast = match expr {
+            Expr::Lit(bytes) if bytes.is_empty() => Ast::Empty,
+            Expr::Lit(bytes) => self.lit(bytes),
+            Expr::Text(bytes) => self.text(bytes),
+            Expr::Bytes(ranges) => {
+                let mut ranges = ranges.clone();
+                canonicalize_ranges(&mut ranges);
+
+                if ranges.is_empty() {
+                    Ast::Fail
+                } else {
+                    self.class_of(&ranges)
+                }
+            }
+            Expr::Chars(chars) => self.unicode_class(chars),
+            Expr::Concat(exprs) | Expr::Alt(exprs) => {
+                let mut children = Vec::with_capacity(exprs.len());
+
+                for expr in exprs {
+                    children.push(self.expr(grammar, expr, stack)?);
+                }
+
+                match (children.len(), expr) {
+                    (0, Expr::Concat(_)) => Ast::Empty,
+                    (0, _) => Ast::Fail,
+                    (1, _) => children.pop().unwrap(),
+                    (_, Expr::Concat(_)) => Ast::Concat(Concat {
+                        children: self.children(children, &[]),
+                    }),
+                    (_, _) => Ast::Alt(Alt {
+                        children: self.children(children, &[]),
+                    }),
+                }
+            }
+            Expr::Repeat(kind, expr, span) => {
+                self.check_repeat(*kind, *span)?;
+                let child = self.expr(grammar, expr, stack)?;
+
+                Ast::Repeat(Repeat {
+                    kind: *kind,
+                    greediness: Greediness::Greedy,
+                    child: self.alloc(child, Span::default()),
+                })
+            }
+            Expr::Group(expr) => {
+                let child = self.expr(grammar, expr, stack)?;
+
+                Ast::Group(Group {
+                    flags: None,
+                    capture: None,
+                    atomic: false,
+                    child: self.alloc(child, Span::default()),
+                })
+            }
+            Expr::Rule(name, span) => {
+                let index = self.reference(grammar, name, *span, stack)?;
+
+                self.enter(*span)?;
+                let ast = self.rule(grammar, index, stack)?;
+                self.depth -= 1;
+
+                // Rules referred to many times can make the expansion exponentially large.
+                self.check_size(*span)?;
+                ast
+            }
+            Expr::Prose(span) => return fail(ParseErrorKind::UnsupportedProse, *span),
+            Expr::Except(expr, exception, span) => {
+                let sets = (
+                    self.byte_set(grammar, expr, stack)?,
+                    self.byte_set(grammar, exception, stack)?,
+                );
+
+                let (Some(ranges), Some(exception)) = sets else {
+                    return fail(ParseErrorKind::UnsupportedException, *span);
+                };
+
+                self.expr(
+                    grammar,
+                    &Expr::Bytes(difference_ranges(&ranges, &exception)),
+                    stack,
+                )?
+            }
+        };
+
+        Ok(ast)
+    }
+
+    /// Build a node matching `bytes` regardless of ASCII case.
+    fn text(&mut self, bytes: &[u8]) -> Ast<'b> {
+        let mut children = Vec::new();
+        let mut start = 0;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            if !byte.is_ascii_alphabetic() {
+                continue;
+            }
+
+            if start < i {
+                children.push(self.lit(&bytes[start..i]));
+            }
+
+            let (upper, lower) = (byte.to_ascii_uppercase(), byte.to_ascii_lowercase());
+            children
+                .push(self.class_of(&[ByteRange::new(upper, upper), ByteRange::new(lower, lower)]));
+
+            start = i + 1;
+        }
+
+        if start < bytes.len() {
+            children.push(self.lit(&bytes[start..]));
+        }
+
+        match children.len() {
+            0 => Ast::Empty,
+            1 => children.pop().unwrap(),
+            _ => Ast::Concat(Concat {
+                children: self.children(children, &[]),
+            }),
+        }
+    }
+
+    /// Get the bytes matched by `expr` if it only matches single bytes.
+    fn byte_set(
+        &self,
+        grammar: &Grammar,
+        expr: &Expr,
+        stack: &mut Vec<usize>,
+    ) -> PResult<Option<Vec<ByteRange>>, ParseError> {
+        let ranges = match expr {
+            Expr::Lit(bytes) => match bytes[..] {
+                [byte] => vec![ByteRange::new(byte, byte)],
+                _ => return Ok(None),
+            },
+            Expr::Text(bytes) => match bytes[..] {
+                [byte] => {
+                    let (upper, lower) = (byte.to_ascii_uppercase(), byte.to_ascii_lowercase());
+                    vec![ByteRange::new(upper, upper), ByteRange::new(lower, lower)]
+                }
+                _ => return Ok(None),
+            },
+            Expr::Bytes(ranges) => ranges.clone(),
+            Expr::Chars(chars) => {
+                if chars.iter().any(|range| !range.end.is_ascii()) {
+                    return Ok(None);
+                }
+
+                chars
+                    .iter()
+                    .map(|range| ByteRange::new(range.start as u8, range.end as u8))
+                    .collect()
+            }
+            Expr::Alt(exprs) => {
+                let mut ranges = Vec::new();
+
+                for expr in exprs {
+                    match self.byte_set(grammar, expr, stack)? {
+                        Some(set) => ranges.extend(set),
+                        None => return Ok(None),
+                    }
+                }
+
+                ranges
+            }
+            Expr::Concat(exprs) if exprs.len() == 1 => {
+                return self.byte_set(grammar, &exprs[0], stack)
+            }
+            Expr::Group(expr) => return self.byte_set(grammar, expr, stack),
+            Expr::Rule(name, span) => {
+                let index = self.reference(grammar, name, *span, stack)?;
+
+                stack.push(index);
+                let set = self.byte_set(grammar, &grammar.rules[index].expr, stack)?;
+                stack.pop();
+
+                return Ok(set);
+            }
+            Expr::Prose(span) => return fail(ParseErrorKind::UnsupportedProse, *span),
+            Expr::Concat(_) | Expr::Repeat(..) | Expr::Except(..) => return Ok(None),
+        };
+
+        Ok(Some(ranges))
+    }
+}
+
+/// The number of groups that an expression is nested in, and the most it may be.
+#[derive(Debug, Clone, Copy)]
+struct Depth {
+    depth: u32,
+    limit: u32,
+}
+
+impl Depth {
+    #[inline]
+    #[must_use]
+    const fn new(limits: Limits) -> Depth {
+        Depth {
+            depth: 0,
+            limit: limits.nest,
+        }
+    }
+
+    /// Get the depth inside a group that starts at `start`, failing if it exceeds the
+    /// limit.
+    fn enter(self, start: usize) -> PResult<Depth, ParseError> {
+        if self.depth >= self.limit {
+            return fail(
+                ParseErrorKind::NestLimitExceeded(self.limit),
+                Span::new(start, start + 1),
+            );
+        }
+
+        Ok(Depth {
+            depth: self.depth + 1,
+            ..self
+        })
+    }
+}
+
+/// Get the span of the next byte of `input`, or of its end.
+#[inline]
+#[must_use]
+fn next_span(input: &Input<'_>) -> Span {
+    let start = input.location();
+    Span::new(start, start + usize::from(!input.is_empty()))
+}
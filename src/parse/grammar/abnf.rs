@@ -0,0 +1,375 @@
+use winnow::{
+    stream::{Located, Location, Stream},
+    PResult,
+};
+
+use super::{core_rules, next_span, Depth, Expr, Grammar};
+use crate::{
+    ast::{CharRange, RepeatKind},
+    parse::{fail, number, peek, peek_nth, span, Input, Limits, ParseError, ParseErrorKind},
+    util::span::Span,
+};
+
+/// Parse the ABNF grammar `grammar`, as defined by RFC 5234 and RFC 7405.
+///
+/// The core rules, such as `ALPHA` and `DIGIT`, are predefined, and rule names are compared
+/// regardless of case. Quoted strings match regardless of ASCII case unless they are
+/// prefixed with `%s`. Numeric values up to 0xFF match single octets, as `OCTET` does, while
+/// larger ones are Unicode scalar values, which are matched by their UTF-8 encodings. A core
+/// rule may be extended with `=/` like any other. Rules containing prose values, as in
+/// `<text>`, cannot be expanded. Lines may end with either CRLF or LF. Groups and
+/// options may be nested at most as deeply as the default [`Limits::nest`].
+pub fn parse_abnf(grammar: &[u8]) -> Result<Grammar, ParseError> {
+    parse_abnf_with(grammar, Limits::DEFAULT)
+}
+
+/// Parse the ABNF grammar `grammar` as [`parse_abnf`] does, within `limits` rather than
+/// the default ones.
+pub fn parse_abnf_with(grammar: &[u8], limits: Limits) -> Result<Grammar, ParseError> {
+    let mut input = Located::new(grammar);
+    let mut grammar = Grammar {
+        rules: core_rules(),
+        case_insensitive: true,
+    };
+
+    rulelist(&mut input, &mut grammar, Depth::new(limits))
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+
+    Ok(grammar)
+}
+
+fn rulelist(input: &mut Input<'_>, grammar: &mut Grammar, depth: Depth) -> PResult<(), ParseError> {
+    loop {
+        // Skip any blank lines and comments between rules.
+        loop {
+            let _ = input.next_slice(input.iter().take_while(|&&b| is_wsp(b)).count());
+            comment(input);
+
+            if !newline(input) {
+                break;
+            }
+        }
+
+        let start = input.location();
+
+        match peek(input) {
+            None => return Ok(()),
+            Some(b) if b.is_ascii_alphabetic() => {}
+            Some(_) => return fail(ParseErrorKind::Unexpected, Span::new(start, start + 1)),
+        }
+
+        let name = rulename(input);
+        let name_span = span(start, input);
+
+        trivia(input);
+
+        let incremental = if input.starts_with(b"=/") {
+            let _ = input.next_slice(2);
+            true
+        } else if peek(input) == Some(b'=') {
+            input.next_token();
+            false
+        } else {
+            return fail(ParseErrorKind::Unexpected, next_span(input));
+        };
+
+        trivia(input);
+        let expr = alternation(input, depth)?;
+        trivia(input);
+
+        // Every rule ends at the end of a line.
+        if !input.is_empty() && !newline(input) {
+            return fail(ParseErrorKind::Unexpected, next_span(input));
+        }
+
+        grammar.define(name, name_span, expr, incremental)?;
+    }
+}
+
+/// Parse a list of alternatives nested in `depth` groups.
+fn alternation(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let mut alternatives = vec![concatenation(input, depth)?];
+
+    loop {
+        let mut ahead = *input;
+        trivia(&mut ahead);
+
+        if peek(&ahead) != Some(b'/') {
+            break;
+        }
+
+        *input = ahead;
+        input.next_token();
+        trivia(input);
+
+        alternatives.push(concatenation(input, depth)?);
+    }
+
+    Ok(Expr::collect(alternatives, Expr::Alt))
+}
+
+fn concatenation(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let mut items = vec![repetition(input, depth)?];
+
+    loop {
+        let mut ahead = *input;
+        trivia(&mut ahead);
+
+        if !matches!(
+            peek(&ahead),
+            Some(b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'*' | b'(' | b'[' | b'"' | b'%' | b'<')
+        ) {
+            break;
+        }
+
+        *input = ahead;
+        items.push(repetition(input, depth)?);
+    }
+
+    Ok(Expr::collect(items, Expr::Concat))
+}
+
+/// Parse an element with an optional repetition prefix, as in `1*DIGIT` or `2HEXDIG`.
+fn repetition(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let start = input.location();
+    let min = number(input)?;
+
+    let kind = if peek(input) == Some(b'*') {
+        input.next_token();
+
+        match (min, number(input)?) {
+            (min, Some(max)) => Some(RepeatKind::Bounded(min.unwrap_or(0), max)),
+            (min, None) => Some(RepeatKind::AtLeast(min.unwrap_or(0))),
+        }
+    } else {
+        min.map(RepeatKind::Exact)
+    };
+
+    let kind = match kind {
+        Some(kind) if !kind.is_valid() => {
+            return fail(
+                ParseErrorKind::InvalidRepeatBounds(kind),
+                span(start, input),
+            )
+        }
+        kind => kind,
+    };
+
+    let element = element(input, depth)?;
+
+    Ok(match kind {
+        Some(kind) => Expr::Repeat(kind, Box::new(element), span(start, input)),
+        None => element,
+    })
+}
+
+fn element(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let start = input.location();
+
+    match peek(input) {
+        Some(b) if b.is_ascii_alphabetic() => {
+            let name = rulename(input);
+            Ok(Expr::Rule(name, span(start, input)))
+        }
+        Some(open @ (b'(' | b'[')) => {
+            let depth = depth.enter(start)?;
+            input.next_token();
+
+            trivia(input);
+            let expr = alternation(input, depth)?;
+            trivia(input);
+
+            let close = if open == b'(' { b')' } else { b']' };
+            if input.next_token() != Some(close) {
+                return fail(ParseErrorKind::UnclosedGroup, Span::new(start, start + 1));
+            }
+
+            Ok(match open {
+                b'(' => Expr::Group(Box::new(expr)),
+                _ => Expr::Repeat(RepeatKind::ZERO_OR_ONE, Box::new(expr), span(start, input)),
+            })
+        }
+        Some(b'"') => quoted(input).map(Expr::Text),
+        Some(b'%') => {
+            input.next_token();
+
+            match peek(input).map(|b| b.to_ascii_lowercase()) {
+                Some(b's') if peek_nth(input, 1) == Some(b'"') => {
+                    input.next_token();
+                    quoted(input).map(Expr::Lit)
+                }
+                Some(b'i') if peek_nth(input, 1) == Some(b'"') => {
+                    input.next_token();
+                    quoted(input).map(Expr::Text)
+                }
+                Some(b'b') => num_val(input, 2),
+                Some(b'd') => num_val(input, 10),
+                Some(b'x') => num_val(input, 16),
+                _ => fail(ParseErrorKind::Unexpected, span(start, input)),
+            }
+        }
+        Some(b'<') => match input.iter().position(|&b| b == b'>') {
+            Some(len) => {
+                let _ = input.next_slice(len + 1);
+                Ok(Expr::Prose(span(start, input)))
+            }
+            None => fail(ParseErrorKind::UnclosedString, Span::new(start, start + 1)),
+        },
+        _ => fail(ParseErrorKind::Unexpected, next_span(input)),
+    }
+}
+
+/// Parse a string quoted with `"`, returning its contents.
+fn quoted(input: &mut Input<'_>) -> PResult<Vec<u8>, ParseError> {
+    let start = input.location();
+    input.next_token();
+
+    match input
+        .iter()
+        .position(|&b| matches!(b, b'"' | b'\r' | b'\n'))
+    {
+        Some(len) if input[len] == b'"' => {
+            let bytes = input.next_slice(len).to_vec();
+            input.next_token();
+
+            Ok(bytes)
+        }
+        _ => fail(ParseErrorKind::UnclosedString, Span::new(start, start + 1)),
+    }
+}
+
+/// Parse the rest of a numeric value in `radix`, as in `%x41-5A` or `%d13.10`.
+///
+/// Values up to 0xFF are octets, and larger ones are scalar values.
+fn num_val(input: &mut Input<'_>, radix: u32) -> PResult<Expr, ParseError> {
+    let start = input.location() - 1;
+    input.next_token();
+
+    let first = value(input, radix)?;
+
+    if peek(input) == Some(b'-') {
+        input.next_token();
+
+        let last = value(input, radix)?;
+        if first > last {
+            return fail(ParseErrorKind::InvalidClassRange, span(start, input));
+        }
+
+        // Any part of the range up to 0xFF is a range of octets.
+        let octets =
+            (first <= '\u{FF}').then(|| Expr::bytes(&[(first as u8, last.min('\u{FF}') as u8)]));
+        let chars = (last > '\u{FF}')
+            .then(|| Expr::Chars(vec![CharRange::new(first.max('\u{100}'), last)]));
+
+        return Ok(Expr::collect(
+            octets.into_iter().chain(chars).collect(),
+            Expr::Alt,
+        ));
+    }
+
+    let mut bytes = Vec::new();
+    let mut c = first;
+
+    loop {
+        match u8::try_from(c) {
+            Ok(byte) => bytes.push(byte),
+            Err(_) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+
+        if peek(input) != Some(b'.') {
+            break;
+        }
+
+        input.next_token();
+        c = value(input, radix)?;
+    }
+
+    Ok(Expr::Lit(bytes))
+}
+
+/// Parse a number in `radix` that must be a Unicode scalar value.
+fn value(input: &mut Input<'_>, radix: u32) -> PResult<char, ParseError> {
+    let start = input.location();
+    let len = input
+        .iter()
+        .take_while(|&&b| (b as char).is_digit(radix))
+        .count();
+
+    if len == 0 {
+        return fail(ParseErrorKind::Unexpected, next_span(input));
+    }
+
+    let value = input.next_slice(len).iter().try_fold(0u32, |n, &digit| {
+        let digit = (digit as char).to_digit(radix)?;
+        n.checked_mul(radix)?.checked_add(digit)
+    });
+
+    match value.and_then(char::from_u32) {
+        Some(c) => Ok(c),
+        None => fail(ParseErrorKind::InvalidCodePoint, span(start, input)),
+    }
+}
+
+/// Consume a rule name, which starts with a letter.
+fn rulename(input: &mut Input<'_>) -> String {
+    let len = input
+        .iter()
+        .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'-')
+        .count();
+
+    String::from_utf8(input.next_slice(len).to_vec()).expect("rule names are ASCII")
+}
+
+/// Consume any whitespace and comments, including line breaks that are followed by
+/// whitespace, which continue the current rule.
+fn trivia(input: &mut Input<'_>) {
+    loop {
+        let _ = input.next_slice(input.iter().take_while(|&&b| is_wsp(b)).count());
+        comment(input);
+
+        let len = if input.starts_with(b"\r\n") {
+            2
+        } else {
+            usize::from(peek(input) == Some(b'\n'))
+        };
+
+        if len == 0 || !peek_nth(input, len).is_some_and(is_wsp) {
+            break;
+        }
+
+        let _ = input.next_slice(len);
+    }
+}
+
+/// Consume a comment up to the end of its line, if one comes next.
+fn comment(input: &mut Input<'_>) {
+    if peek(input) == Some(b';') {
+        let len = input
+            .iter()
+            .position(|&b| matches!(b, b'\r' | b'\n'))
+            .unwrap_or(input.eof_offset());
+
+        let _ = input.next_slice(len);
+    }
+}
+
+/// Consume a line break, if one comes next.
+fn newline(input: &mut Input<'_>) -> bool {
+    if input.starts_with(b"\r\n") {
+        let _ = input.next_slice(2);
+        return true;
+    }
+
+    if peek(input) == Some(b'\n') {
+        input.next_token();
+        return true;
+    }
+
+    false
+}
+
+#[inline]
+#[must_use]
+const fn is_wsp(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t')
+}
@@ -0,0 +1,263 @@
+use winnow::{
+    stream::{Located, Location, Stream},
+    PResult,
+};
+
+use super::{next_span, Depth, Expr, Grammar};
+use crate::{
+    ast::RepeatKind,
+    parse::{fail, number, peek, span, Input, Limits, ParseError, ParseErrorKind},
+    util::span::Span,
+};
+
+/// Parse the EBNF grammar `grammar`, as defined by ISO/IEC 14977.
+///
+/// Rules end with `;` or `.`, and their alternatives are separated by `|`, `/` or `!`.
+/// Terminal strings are quoted with `'` or `"`, and are matched exactly. Spaces within
+/// a meta identifier are insignificant, so `digit excluding zero` is a single name. An
+/// exception, as in `letter - "x"`, can only be expanded if both of its operands only match
+/// single bytes. Rules containing special sequences, as in `? text ?`, cannot be expanded.
+/// Groups, options and repetitions may be nested at most as deeply as the default
+/// [`Limits::nest`].
+pub fn parse_ebnf(grammar: &[u8]) -> Result<Grammar, ParseError> {
+    parse_ebnf_with(grammar, Limits::DEFAULT)
+}
+
+/// Parse the EBNF grammar `grammar` as [`parse_ebnf`] does, within `limits` rather than
+/// the default ones.
+pub fn parse_ebnf_with(grammar: &[u8], limits: Limits) -> Result<Grammar, ParseError> {
+    let mut input = Located::new(grammar);
+    let mut grammar = Grammar {
+        rules: Vec::new(),
+        case_insensitive: false,
+    };
+
+    syntax(&mut input, &mut grammar, Depth::new(limits))
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+
+    Ok(grammar)
+}
+
+fn syntax(input: &mut Input<'_>, grammar: &mut Grammar, depth: Depth) -> PResult<(), ParseError> {
+    loop {
+        gap(input)?;
+
+        let start = input.location();
+
+        match peek(input) {
+            None => return Ok(()),
+            Some(b) if b.is_ascii_alphabetic() => {}
+            Some(_) => return fail(ParseErrorKind::Unexpected, Span::new(start, start + 1)),
+        }
+
+        let name = identifier(input);
+        let name_span = span(start, input);
+
+        gap(input)?;
+
+        if peek(input) != Some(b'=') {
+            return fail(ParseErrorKind::Unexpected, next_span(input));
+        }
+
+        input.next_token();
+        let expr = definitions(input, depth)?;
+
+        if !matches!(peek(input), Some(b';' | b'.')) {
+            return fail(ParseErrorKind::Unexpected, next_span(input));
+        }
+
+        input.next_token();
+        grammar.define(name, name_span, expr, false)?;
+    }
+}
+
+/// Parse a list of alternatives nested in `depth` groups, as in `a | b`, along with any
+/// trailing gap.
+fn definitions(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let mut alternatives = vec![definition(input, depth)?];
+
+    while matches!(peek(input), Some(b'|' | b'/' | b'!')) {
+        input.next_token();
+        alternatives.push(definition(input, depth)?);
+    }
+
+    Ok(Expr::collect(alternatives, Expr::Alt))
+}
+
+/// Parse a concatenation, as in `a, b`, along with any trailing gap.
+fn definition(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    let mut terms = vec![term(input, depth)?];
+
+    while peek(input) == Some(b',') {
+        input.next_token();
+        terms.push(term(input, depth)?);
+    }
+
+    Ok(Expr::collect(terms, Expr::Concat))
+}
+
+/// Parse a factor with an optional exception, as in `letter - "x"`, along with any
+/// trailing gap.
+fn term(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    gap(input)?;
+
+    let start = input.location();
+    let expr = factor(input, depth)?;
+
+    if peek(input) != Some(b'-') {
+        return Ok(expr);
+    }
+
+    input.next_token();
+    let exception = factor(input, depth)?;
+
+    Ok(Expr::Except(
+        Box::new(expr),
+        Box::new(exception),
+        span(start, input),
+    ))
+}
+
+/// Parse a primary with an optional repetition count, as in `3 * "a"`, along with any
+/// trailing gap.
+fn factor(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    gap(input)?;
+
+    let start = input.location();
+    let Some(count) = number(input)? else {
+        return primary(input, depth);
+    };
+
+    gap(input)?;
+
+    if peek(input) != Some(b'*') {
+        return fail(ParseErrorKind::Unexpected, next_span(input));
+    }
+
+    input.next_token();
+    let expr = primary(input, depth)?;
+
+    Ok(Expr::Repeat(
+        RepeatKind::Exact(count),
+        Box::new(expr),
+        span(start, input),
+    ))
+}
+
+/// Parse a single item, which may be empty, along with any trailing gap.
+fn primary(input: &mut Input<'_>, depth: Depth) -> PResult<Expr, ParseError> {
+    gap(input)?;
+
+    let start = input.location();
+
+    let expr = match peek(input) {
+        Some(open @ (b'[' | b'{' | b'(')) => {
+            let depth = depth.enter(start)?;
+            input.next_token();
+            let expr = definitions(input, depth)?;
+
+            let close = match open {
+                b'[' => b']',
+                b'{' => b'}',
+                _ => b')',
+            };
+
+            if input.next_token() != Some(close) {
+                return fail(ParseErrorKind::UnclosedGroup, Span::new(start, start + 1));
+            }
+
+            match open {
+                b'[' => Expr::Repeat(RepeatKind::ZERO_OR_ONE, Box::new(expr), span(start, input)),
+                b'{' => Expr::Repeat(RepeatKind::ZERO_OR_MORE, Box::new(expr), span(start, input)),
+                _ => Expr::Group(Box::new(expr)),
+            }
+        }
+        Some(quote @ (b'\'' | b'"')) => {
+            input.next_token();
+
+            match input.iter().position(|&b| b == quote) {
+                Some(len) => {
+                    let bytes = input.next_slice(len).to_vec();
+                    input.next_token();
+
+                    Expr::Lit(bytes)
+                }
+                None => return fail(ParseErrorKind::UnclosedString, Span::new(start, start + 1)),
+            }
+        }
+        Some(b'?') => match input[1..].iter().position(|&b| b == b'?') {
+            Some(len) => {
+                let _ = input.next_slice(len + 2);
+                Expr::Prose(span(start, input))
+            }
+            None => return fail(ParseErrorKind::UnclosedString, Span::new(start, start + 1)),
+        },
+        Some(b) if b.is_ascii_alphabetic() => {
+            let name = identifier(input);
+            Expr::Rule(name, span(start, input))
+        }
+        // Anything else ends an empty sequence.
+        _ => Expr::empty(),
+    };
+
+    gap(input)?;
+
+    Ok(expr)
+}
+
+/// Consume a meta identifier, which starts with a letter.
+///
+/// The words of an identifier that is split by whitespace are joined by a single space.
+fn identifier(input: &mut Input<'_>) -> String {
+    let mut name = Vec::new();
+
+    loop {
+        let len = input
+            .iter()
+            .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_')
+            .count();
+        name.extend_from_slice(input.next_slice(len));
+
+        let mut ahead = *input;
+        let _ = ahead.next_slice(ahead.iter().take_while(|b| b.is_ascii_whitespace()).count());
+
+        if !peek(&ahead).is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_') {
+            break;
+        }
+
+        *input = ahead;
+        name.push(b' ');
+    }
+
+    String::from_utf8(name).expect("meta identifiers are ASCII")
+}
+
+/// Consume any whitespace and comments, which may be nested, as in `(* a (* b *) *)`.
+fn gap(input: &mut Input<'_>) -> PResult<(), ParseError> {
+    loop {
+        let _ = input.next_slice(input.iter().take_while(|b| b.is_ascii_whitespace()).count());
+
+        if !input.starts_with(b"(*") {
+            return Ok(());
+        }
+
+        let start = input.location();
+        let mut depth = 0usize;
+
+        loop {
+            if input.starts_with(b"(*") {
+                depth += 1;
+                let _ = input.next_slice(2);
+            } else if input.starts_with(b"*)") {
+                depth -= 1;
+                let _ = input.next_slice(2);
+
+                if depth == 0 {
+                    break;
+                }
+            } else if input.next_token().is_none() {
+                return fail(ParseErrorKind::UnclosedComment, Span::new(start, start + 2));
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/// Limits on the resources used to parse a single pattern.
+///
+/// Patterns from untrusted sources can be crafted to exhaust the stack or memory, as with
+/// thousands of nested groups, or to make any automaton built from them enormous, as with
+/// `a{4294967295}`. A pattern that exceeds any of these limits is rejected with an error
+/// naming the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Limits {
+    /// The maximum number of groups and bracket classes that may be nested in each other.
+    ///
+    /// Each level of nesting takes several kilobytes of stack in unoptimized builds, so the
+    /// default keeps parsing within the 2 MiB stack that spawned threads get by default.
+    pub nest: u32,
+    /// The maximum number of nodes that may be placed in the arena.
+    pub nodes: usize,
+    /// The maximum number of bytes that the arena may grow by.
+    ///
+    /// The arena allocates memory in chunks, so this is compared against the total size of
+    /// the chunks allocated while parsing, rather than the size of the nodes themselves.
+    pub arena_bytes: usize,
+    /// The maximum bound of a counted repetition, as in `a{1000}`.
+    pub repeat: u32,
+}
+
+impl Limits {
+    /// Limits that accept any reasonable pattern.
+    pub const DEFAULT: Limits = Limits {
+        nest: 128,
+        nodes: 1 << 20,
+        arena_bytes: 64 << 20,
+        repeat: 1000,
+    };
+
+    /// No limits at all.
+    ///
+    /// Deeply nested patterns may still overflow the stack while parsing.
+    pub const NONE: Limits = Limits {
+        nest: u32::MAX,
+        nodes: usize::MAX,
+        arena_bytes: usize::MAX,
+        repeat: u32::MAX,
+    };
+}
+
+impl Default for Limits {
+    #[inline]
+    fn default() -> Limits {
+        Limits::DEFAULT
+    }
+}
@@ -0,0 +1,236 @@
+use bumpalo::Bump;
+use winnow::{
+    stream::{Located, Location, Stream},
+    PResult,
+};
+
+use super::{
+    fail, peek, span, Atom, Char, Input, Items, ParseError, ParseErrorKind, Parser, Syntax,
+};
+use crate::{
+    ast::{Alt, Ast, Greediness, Group, Repeat, RepeatKind},
+    util::span::Span,
+};
+
+/// Parse the SQL `LIKE` pattern `pattern` into an [`Ast`] allocated in `bump`, which
+/// matches exactly the strings that the pattern matches.
+///
+/// `%` matches any number of bytes and `_` any single UTF-8 encoded scalar value. If there
+/// is an `escape` byte,
+/// as in `LIKE 'a!%' ESCAPE '!'`, it must be followed by `%`, `_` or itself, which is then
+/// matched literally.
+pub fn parse_like<'b>(
+    pattern: &[u8],
+    escape: Option<u8>,
+    bump: &'b Bump,
+) -> Result<Ast<'b>, ParseError> {
+    let mut input = Located::new(pattern);
+    let mut parser = Parser::new(bump, Syntax::DEFAULT, false);
+
+    let mut items = Items::default();
+
+    while let Some(byte) = peek(&input) {
+        let start = input.location();
+
+        let ast = match byte {
+            _ if Some(byte) == escape => {
+                input.next_token();
+
+                match input.next_token() {
+                    Some(next) if next == byte || next == b'%' || next == b'_' => {
+                        items.push_char(Char::byte(next), span(start, &input));
+                        continue;
+                    }
+                    Some(_) => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::InvalidEscape,
+                            span(start, &input),
+                        ))
+                    }
+                    None => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::DanglingEscape,
+                            span(start, &input),
+                        ))
+                    }
+                }
+            }
+            b'%' => {
+                input.next_token();
+
+                Ast::Repeat(Repeat {
+                    kind: RepeatKind::ZERO_OR_MORE,
+                    greediness: Greediness::Greedy,
+                    child: parser.alloc(Ast::Dot, span(start, &input)),
+                })
+            }
+            b'_' => {
+                input.next_token();
+                parser.any_char()
+            }
+            _ => {
+                let c = Char::next(&mut input);
+                items.push_char(c, span(start, &input));
+                continue;
+            }
+        };
+
+        parser
+            .check_size(span(start, &input))
+            .map_err(|err| err.into_inner().expect("input is never partial"))?;
+        items.push(&parser, ast, span(start, &input));
+    }
+
+    Ok(items.finish(&mut parser))
+}
+
+/// Parse the SQL `SIMILAR TO` pattern `pattern` into an [`Ast`] allocated in `bump`, which
+/// matches exactly the strings that the pattern matches.
+///
+/// As in `LIKE` patterns, `%` matches any number of bytes and `_` any single UTF-8 encoded
+/// scalar value. The
+/// operators `|`, `*`, `+`, `?` and `{m,n}`, groups and bracket expressions are as in
+/// POSIX extended regular expressions, while any other character, including `.`, `^` and
+/// `$`, is a literal. If there is an `escape` byte, any character following it is matched
+/// literally.
+pub fn parse_similar<'b>(
+    pattern: &[u8],
+    escape: Option<u8>,
+    bump: &'b Bump,
+) -> Result<Ast<'b>, ParseError> {
+    let mut input = Located::new(pattern);
+    let mut parser = Parser::new(bump, Syntax::POSIX_EXTENDED, false);
+
+    let ast = parser
+        .similar_alternation(&mut input, escape)
+        .map_err(|err| err.into_inner().expect("input is never partial"))?;
+
+    // `similar_concat` only stops at a `|` or `)`, and `similar_alternation` consumes
+    // every `|`.
+    match peek(&input) {
+        None => Ok(ast),
+        Some(_) => {
+            let start = input.location();
+            Err(ParseError::new(
+                ParseErrorKind::UnopenedGroup,
+                Span::new(start, start + 1),
+            ))
+        }
+    }
+}
+
+impl<'b> Parser<'b> {
+    fn similar_alternation(
+        &mut self,
+        input: &mut Input<'_>,
+        escape: Option<u8>,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let start = input.location();
+        let first = self.similar_concat(input, escape)?;
+
+        if peek(input) != Some(b'|') {
+            return Ok(first);
+        }
+
+        let mut children = vec![first];
+        let mut spans = vec![span(start, input)];
+
+        while peek(input) == Some(b'|') {
+            input.next_token();
+
+            let start = input.location();
+            children.push(self.similar_concat(input, escape)?);
+            spans.push(span(start, input));
+        }
+
+        Ok(Ast::Alt(Alt {
+            children: self.children(children, &spans),
+        }))
+    }
+
+    fn similar_concat(
+        &mut self,
+        input: &mut Input<'_>,
+        escape: Option<u8>,
+    ) -> PResult<Ast<'b>, ParseError> {
+        let mut items = Items::default();
+
+        while let Some(byte) = peek(input) {
+            let start = input.location();
+
+            let atom = match byte {
+                _ if Some(byte) == escape => {
+                    input.next_token();
+
+                    if input.is_empty() {
+                        return fail(ParseErrorKind::DanglingEscape, span(start, input));
+                    }
+
+                    Atom::Char(Char::next(input))
+                }
+                b'|' | b')' => break,
+                b'%' => {
+                    input.next_token();
+
+                    Atom::Ast(Ast::Repeat(Repeat {
+                        kind: RepeatKind::ZERO_OR_MORE,
+                        greediness: Greediness::Greedy,
+                        child: self.alloc(Ast::Dot, span(start, input)),
+                    }))
+                }
+                b'_' => {
+                    input.next_token();
+                    Atom::Ast(self.any_char())
+                }
+                b'(' => {
+                    input.next_token();
+
+                    self.enter(Span::new(start, start + 1))?;
+
+                    let child_start = input.location();
+                    let child = self.similar_alternation(input, escape)?;
+                    let child_span = span(child_start, input);
+
+                    self.depth -= 1;
+
+                    if input.next_token() != Some(b')') {
+                        return fail(ParseErrorKind::UnclosedGroup, Span::new(start, start + 1));
+                    }
+
+                    Atom::Ast(Ast::Group(Group {
+                        flags: None,
+                        capture: None,
+                        atomic: false,
+                        child: self.alloc(child, child_span),
+                    }))
+                }
+                b'[' => Atom::Ast(self.class(input)?),
+                b'*' | b'+' | b'?' | b'{' => {
+                    return fail(
+                        ParseErrorKind::MissingRepeatOperand,
+                        Span::new(start, start + 1),
+                    )
+                }
+                _ => Atom::Char(Char::next(input)),
+            };
+
+            let ast = match atom {
+                // Adjacent characters are merged into a single literal, unless
+                // the character is the operand of a repetition.
+                Atom::Char(c) if !self.repeat_next(input) => {
+                    items.push_char(c, span(start, input));
+                    continue;
+                }
+                Atom::Char(c) => self.lit(c.as_bytes()),
+                Atom::Ast(ast) => ast,
+                Atom::Quoted(_) => unreachable!(),
+            };
+
+            let ast = self.repeat(input, ast, start)?;
+            self.check_size(span(start, input))?;
+            items.push(self, ast, span(start, input));
+        }
+
+        Ok(items.finish(self))
+    }
+}
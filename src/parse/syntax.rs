@@ -0,0 +1,218 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use super::{Dialect, Limits};
+use crate::ast::Flags;
+
+/// The options that control how a pattern is parsed.
+///
+/// Each of the presets follows the regex engine it is named after as closely as this
+/// crate's syntax allows. Its fields can then be changed individually.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Syntax {
+    /// The grammar that the pattern is written in.
+    pub dialect: Dialect,
+    /// The flags that are active at the start of the pattern.
+    pub flags: Flags,
+    /// The escape sequences that may be used.
+    pub escapes: Escapes,
+    /// Whether a `{` that does not start a valid counted repetition is a literal, instead
+    /// of an error.
+    pub literal_brace: bool,
+    /// Whether `$` also matches before a `\n` at the end of the input.
+    pub dollar_before_newline: bool,
+    /// Whether a repetition followed by `+`, as in `a*+`, is possessive, instead of an
+    /// error.
+    pub possessive: bool,
+    /// Whether `&&`, `--` and `~~` in a bracket class are the intersection, difference and
+    /// symmetric difference of the items on either side, instead of literals.
+    pub class_set_operations: bool,
+    /// Whether a `[` in a bracket class starts a nested class, instead of being a literal.
+    pub nested_classes: bool,
+    /// Whether a `]` at the start of a bracket class, as in `[]a]`, is a literal, instead
+    /// of closing it.
+    pub literal_bracket: bool,
+    /// Whether `[]` is a class that matches nothing and `[^]` one that matches anything,
+    /// instead of an error.
+    pub empty_class: bool,
+    /// The limits on the resources used to parse a pattern.
+    pub limits: Limits,
+}
+
+impl Syntax {
+    /// The default syntax, which is similar to that of Perl, and supports every escape.
+    pub const DEFAULT: Syntax = Syntax {
+        dialect: Dialect::Default,
+        flags: Flags::EMPTY,
+        escapes: Escapes::ALL,
+        literal_brace: false,
+        dollar_before_newline: false,
+        possessive: true,
+        class_set_operations: true,
+        nested_classes: true,
+        literal_bracket: false,
+        empty_class: false,
+        limits: Limits::DEFAULT,
+    };
+
+    /// The syntax of PCRE2.
+    pub const PCRE: Syntax = Syntax {
+        escapes: Escapes::ALL.difference(Escapes::UNICODE),
+        literal_brace: true,
+        dollar_before_newline: true,
+        class_set_operations: false,
+        nested_classes: false,
+        literal_bracket: true,
+        ..Syntax::DEFAULT
+    };
+
+    /// The syntax of ECMAScript, as used by JavaScript.
+    pub const ECMASCRIPT: Syntax = Syntax {
+        escapes: Escapes::HEX
+            .union(Escapes::UNICODE)
+            .union(Escapes::CONTROL)
+            .union(Escapes::PROPERTY),
+        literal_brace: true,
+        possessive: false,
+        class_set_operations: false,
+        nested_classes: false,
+        empty_class: true,
+        ..Syntax::DEFAULT
+    };
+
+    /// The syntax of RE2, which matches UTF-8 by default.
+    pub const RE2: Syntax = Syntax {
+        flags: Flags::UNICODE,
+        escapes: Escapes::BELL
+            .union(Escapes::HEX)
+            .union(Escapes::OCTAL)
+            .union(Escapes::QUOTE)
+            .union(Escapes::PROPERTY)
+            .union(Escapes::ANCHOR),
+        literal_brace: true,
+        possessive: false,
+        class_set_operations: false,
+        nested_classes: false,
+        literal_bracket: true,
+        ..Syntax::DEFAULT
+    };
+
+    /// The syntax of the `regex` crate for Rust, which matches UTF-8 by default.
+    pub const RUST: Syntax = Syntax {
+        flags: Flags::UNICODE,
+        escapes: Escapes::BELL
+            .union(Escapes::HEX)
+            .union(Escapes::UNICODE)
+            .union(Escapes::PROPERTY)
+            .union(Escapes::ANCHOR),
+        possessive: false,
+        literal_bracket: true,
+        ..Syntax::DEFAULT
+    };
+
+    /// POSIX basic regular expressions.
+    pub const POSIX_BASIC: Syntax = Syntax {
+        dialect: Dialect::PosixBasic,
+        class_set_operations: false,
+        nested_classes: false,
+        literal_bracket: true,
+        ..Syntax::DEFAULT
+    };
+
+    /// POSIX extended regular expressions.
+    pub const POSIX_EXTENDED: Syntax = Syntax {
+        dialect: Dialect::PosixExtended,
+        ..Syntax::POSIX_BASIC
+    };
+}
+
+impl Default for Syntax {
+    #[inline]
+    fn default() -> Syntax {
+        Syntax::DEFAULT
+    }
+}
+
+impl From<Dialect> for Syntax {
+    #[inline]
+    fn from(dialect: Dialect) -> Syntax {
+        match dialect {
+            Dialect::Default => Syntax::DEFAULT,
+            Dialect::PosixBasic => Syntax::POSIX_BASIC,
+            Dialect::PosixExtended => Syntax::POSIX_EXTENDED,
+        }
+    }
+}
+
+/// A set of escape sequences that may be disabled.
+///
+/// Escapes for punctuation, `\n`, `\t` and the like, and Perl classes such as `\d` are
+/// always enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Escapes(u16);
+
+impl Escapes {
+    pub const EMPTY: Escapes = Escapes(0);
+    pub const ALL: Escapes = Escapes((1 << 10) - 1);
+
+    /// `\a`, for the bell character.
+    pub const BELL: Escapes = Escapes(1 << 0);
+    /// `\e`, for the escape character.
+    pub const ESCAPE: Escapes = Escapes(1 << 1);
+    /// `\x41` and `\x{41}`.
+    pub const HEX: Escapes = Escapes(1 << 2);
+    /// `\u0041` and `\u{41}`.
+    pub const UNICODE: Escapes = Escapes(1 << 3);
+    /// `\o{101}` and `\0` followed by up to two octal digits, as in `\012`.
+    pub const OCTAL: Escapes = Escapes(1 << 4);
+    /// `\cA`, for control characters.
+    pub const CONTROL: Escapes = Escapes(1 << 5);
+    /// `\Q...\E`, which quotes everything between them.
+    pub const QUOTE: Escapes = Escapes(1 << 6);
+    /// `\p` and `\P`, for Unicode properties.
+    pub const PROPERTY: Escapes = Escapes(1 << 7);
+    /// `\A` and `\z`, for the start and end of the input.
+    pub const ANCHOR: Escapes = Escapes(1 << 8);
+    /// `\Z`, for the end of the input or the position before a `\n` that ends it.
+    pub const ANCHOR_NEWLINE: Escapes = Escapes(1 << 9);
+
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Escapes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Escapes) -> Escapes {
+        Escapes(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Escapes) -> Escapes {
+        Escapes(self.0 & !other.0)
+    }
+}
+
+impl BitOr for Escapes {
+    type Output = Escapes;
+
+    #[inline]
+    fn bitor(self, rhs: Escapes) -> Escapes {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Escapes {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Escapes) {
+        *self = self.union(rhs);
+    }
+}
@@ -0,0 +1,169 @@
+#[rustfmt::skip]
+mod case_folding;
+#[rustfmt::skip]
+mod general_category;
+#[rustfmt::skip]
+mod property;
+#[rustfmt::skip]
+mod script;
+mod utf8;
+
+pub use utf8::*;
+
+use std::{cmp::Ordering, sync::OnceLock};
+
+use crate::ast::{canonicalize_ranges, complement_ranges, CharRange};
+
+/// The version of Unicode that the tables are generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Get the sorted ranges of scalar values that have the property `query`, as in `\p{query}`.
+///
+/// `query` is either the name of a general category, binary property or script, in that
+/// order of precedence, or a `name=value` pair for the `General_Category` and `Script`
+/// properties. Names are matched loosely, ignoring case, spaces, underscores, hyphens and
+/// any leading `is`.
+#[must_use]
+pub fn property(query: &str) -> Option<&'static [(char, char)]> {
+    match query.split_once(['=', ':']) {
+        Some((name, value)) => {
+            let (by_name, aliases) = match loose(name).as_str() {
+                "gc" | "generalcategory" => (general_category::BY_NAME, general_category::ALIASES),
+                "sc" | "script" => (script::BY_NAME, script::ALIASES),
+                _ => return None,
+            };
+
+            lookup(by_name, aliases, value)
+        }
+        None => lookup(general_category::BY_NAME, general_category::ALIASES, query)
+            .or_else(|| lookup(property::BY_NAME, property::ALIASES, query))
+            .or_else(|| lookup(script::BY_NAME, script::ALIASES, query)),
+    }
+}
+
+/// Get the sorted ranges of scalar values in the Unicode version of the Perl class for the
+/// letter following a `\`, as in `\d` or `\W`.
+///
+/// `\d` matches decimal numbers, `\s` white space and `\w` alphabetic characters, marks,
+/// decimal numbers, connector punctuation and joiners, as in Unicode Technical Standard #18.
+#[must_use]
+pub fn perl(letter: u8) -> Option<Vec<CharRange>> {
+    let names: &[&str] = match letter.to_ascii_lowercase() {
+        b'd' => &["Nd"],
+        b's' => &["White_Space"],
+        b'w' => &["Alphabetic", "M", "Nd", "Pc", "Join_Control"],
+        _ => return None,
+    };
+
+    let mut ranges = Vec::new();
+
+    for name in names {
+        let property = property(name).expect("property exists");
+        ranges.extend(property.iter().map(|&range| CharRange::from(range)));
+    }
+
+    canonicalize_ranges(&mut ranges);
+
+    Some(if letter.is_ascii_uppercase() {
+        complement_ranges(&ranges)
+    } else {
+        ranges
+    })
+}
+
+/// Check whether `c` is matched by `\w` in Unicode mode.
+#[must_use]
+pub fn is_word_char(c: char) -> bool {
+    static WORD: OnceLock<Vec<CharRange>> = OnceLock::new();
+
+    let ranges = WORD.get_or_init(|| perl(b'w').expect("`\\w` is a Perl class"));
+
+    ranges
+        .binary_search_by(|range| {
+            if range.end < c {
+                Ordering::Less
+            } else if range.start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Get the other scalar values that are equivalent to `c` under simple case folding, as `σ`
+/// and `ς` are to `Σ`.
+///
+/// The Turkic mappings of `I` to `ı` and of `İ` to `i` are not included.
+#[must_use]
+pub fn simple_case_fold(c: char) -> &'static [char] {
+    case_folding::SIMPLE
+        .binary_search_by_key(&c, |&(c, _)| c)
+        .map_or(&[], |i| case_folding::SIMPLE[i].1)
+}
+
+/// Add every scalar value that is equivalent under simple case folding to one in `ranges`,
+/// which then need to be canonicalized.
+pub fn add_simple_case_folds(ranges: &mut Vec<CharRange>) {
+    for i in 0..ranges.len() {
+        let CharRange { start, end } = ranges[i];
+        let first = case_folding::SIMPLE.partition_point(|&(c, _)| c < start);
+
+        for &(_, others) in case_folding::SIMPLE[first..]
+            .iter()
+            .take_while(|&&(c, _)| c <= end)
+        {
+            ranges.extend(others.iter().map(|&c| CharRange::new(c, c)));
+        }
+    }
+}
+
+/// Get the scalar value after `c`, skipping surrogates.
+#[inline]
+#[must_use]
+pub(crate) fn successor(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => char::from_u32(u32::from(c) + 1),
+    }
+}
+
+/// Get the scalar value before `c`, skipping surrogates.
+#[inline]
+#[must_use]
+pub(crate) fn predecessor(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        c => char::from_u32(u32::from(c).checked_sub(1)?),
+    }
+}
+
+fn lookup(
+    by_name: &'static [(&str, &'static [(char, char)])],
+    aliases: &[(&str, &'static str)],
+    name: &str,
+) -> Option<&'static [(char, char)]> {
+    let find = |name: &str| {
+        let name = aliases
+            .binary_search_by_key(&name, |&(alias, _)| alias)
+            .map_or(name, |i| aliases[i].1);
+
+        by_name
+            .binary_search_by_key(&name, |&(name, _)| name)
+            .ok()
+            .map(|i| by_name[i].1)
+    };
+
+    let name = loose(name);
+
+    find(&name).or_else(|| find(name.strip_prefix("is")?))
+}
+
+/// Get the loosely matched form of `name`.
+#[must_use]
+fn loose(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
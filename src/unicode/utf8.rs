@@ -0,0 +1,168 @@
+use std::{fmt, iter::FusedIterator, slice};
+
+use crate::ast::{ByteRange, CharRange};
+
+/// The most bytes a scalar value can be encoded with.
+const MAX_LEN: usize = 4;
+
+/// A sequence of byte ranges that matches the UTF-8 encoding of a range of scalar values.
+///
+/// A sequence matches an encoding if each of its bytes is in the corresponding range.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf8Sequence {
+    ranges: [ByteRange; MAX_LEN],
+    len: u8,
+}
+
+impl Utf8Sequence {
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[ByteRange] {
+        &self.ranges[..self.len as usize]
+    }
+
+    /// Check whether `bytes` is matched by this sequence.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() == self.as_slice().len()
+            && self
+                .as_slice()
+                .iter()
+                .zip(bytes)
+                .all(|(range, byte)| range.to_inclusive().contains(byte))
+    }
+}
+
+impl fmt::Debug for Utf8Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/// An iterator over the [`Utf8Sequence`]s that together match exactly the UTF-8
+/// encodings of a set of scalar values.
+///
+/// The sequences are disjoint, and are yielded in the order of the values they match.
+#[derive(Debug, Clone)]
+pub struct Utf8Sequences<'a> {
+    ranges: slice::Iter<'a, CharRange>,
+    /// The ranges of the current range of `ranges` that remain to be split.
+    stack: Vec<(u32, u32)>,
+}
+
+impl<'a> Utf8Sequences<'a> {
+    /// Create an iterator over the sequences for `ranges`, which must be sorted.
+    #[inline]
+    #[must_use]
+    pub fn new(ranges: &'a [CharRange]) -> Utf8Sequences<'a> {
+        Utf8Sequences {
+            ranges: ranges.iter(),
+            stack: Vec::new(),
+        }
+    }
+}
+
+impl Iterator for Utf8Sequences<'_> {
+    type Item = Utf8Sequence;
+
+    fn next(&mut self) -> Option<Utf8Sequence> {
+        'outer: loop {
+            let (start, mut end) = match self.stack.pop() {
+                Some(range) => range,
+                None => {
+                    let range = self.ranges.next()?;
+                    (u32::from(range.start), u32::from(range.end))
+                }
+            };
+
+            'inner: loop {
+                // Surrogates are not scalar values, so they have no encoding.
+                if start < 0xE000 && end > 0xD7FF {
+                    if end >= 0xE000 {
+                        self.stack.push((0xE000, end));
+                    }
+
+                    end = 0xD7FF;
+
+                    if start > end {
+                        continue 'outer;
+                    }
+                }
+
+                // Split the range so that every value in it has the same length.
+                for len in 1..MAX_LEN {
+                    let max = max_value(len);
+
+                    if start <= max && max < end {
+                        self.stack.push((max + 1, end));
+                        end = max;
+                        continue 'inner;
+                    }
+                }
+
+                if end <= max_value(1) {
+                    let mut ranges = [ByteRange::default(); MAX_LEN];
+                    ranges[0] = ByteRange::new(start as u8, end as u8);
+
+                    return Some(Utf8Sequence { ranges, len: 1 });
+                }
+
+                // Split the range so that every continuation byte covers all of its values,
+                // except for those shared by every value.
+                for i in 1..MAX_LEN {
+                    let mask = (1 << (6 * i)) - 1;
+
+                    if start & !mask != end & !mask {
+                        if start & mask != 0 {
+                            self.stack.push(((start | mask) + 1, end));
+                            end = start | mask;
+                            continue 'inner;
+                        }
+
+                        if end & mask != mask {
+                            self.stack.push((end & !mask, end));
+                            end = (end & !mask) - 1;
+                            continue 'inner;
+                        }
+                    }
+                }
+
+                let (mut lo, mut hi) = ([0; MAX_LEN], [0; MAX_LEN]);
+                let lo = encode(start, &mut lo);
+                let hi = encode(end, &mut hi);
+
+                let mut ranges = [ByteRange::default(); MAX_LEN];
+                for (range, (&start, &end)) in ranges.iter_mut().zip(lo.iter().zip(hi)) {
+                    *range = ByteRange::new(start, end);
+                }
+
+                return Some(Utf8Sequence {
+                    ranges,
+                    len: lo.len() as u8,
+                });
+            }
+        }
+    }
+}
+
+impl FusedIterator for Utf8Sequences<'_> {}
+
+/// Get the greatest value that is encoded with `len` bytes.
+#[inline]
+#[must_use]
+const fn max_value(len: usize) -> u32 {
+    match len {
+        1 => 0x7F,
+        2 => 0x7FF,
+        3 => 0xFFFF,
+        _ => 0x10FFFF,
+    }
+}
+
+#[inline]
+#[must_use]
+fn encode(value: u32, buf: &mut [u8; MAX_LEN]) -> &[u8] {
+    let c = char::from_u32(value).expect("value is a scalar value");
+    c.encode_utf8(buf).as_bytes()
+}
@@ -3,6 +3,9 @@ use std::fmt::{self, Formatter, Write};
 /// Utilities for slices.
 pub mod slice;
 
+/// Byte spans into patterns.
+pub mod span;
+
 pub fn display_bytes(bytes: &[u8], f: &mut Formatter) -> fmt::Result {
     for chunk in bytes.utf8_chunks() {
         f.write_str(chunk.valid())?;
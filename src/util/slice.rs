@@ -39,7 +39,7 @@ impl<T> NonEmpty<T> {
     #[track_caller]
     pub const unsafe fn new_unchecked(slice: &[T]) -> &NonEmpty<T> {
         unsafe {
-            assert_unchecked(slice.len() > 0);
+            assert_unchecked(!slice.is_empty());
 
             &*(slice as *const [T] as *const NonEmpty<T>)
         }
@@ -47,9 +47,8 @@ impl<T> NonEmpty<T> {
 
     /// Try to get a reference to a non-empty slice.
     #[inline]
-    #[must_use]
     pub const fn try_new(slice: &[T]) -> Result<&NonEmpty<T>, &[T]> {
-        if slice.len() > 0 {
+        if !slice.is_empty() {
             // SAFETY: slice is not empty
             Ok(unsafe { NonEmpty::new_unchecked(slice) })
         } else {
@@ -82,7 +81,7 @@ impl<T> NonEmpty<T> {
     #[track_caller]
     pub unsafe fn new_unchecked_mut(slice: &mut [T]) -> &mut NonEmpty<T> {
         unsafe {
-            assert_unchecked(slice.len() > 0);
+            assert_unchecked(!slice.is_empty());
 
             &mut *(slice as *mut [T] as *mut NonEmpty<T>)
         }
@@ -90,9 +89,8 @@ impl<T> NonEmpty<T> {
 
     /// Try to get a mutable reference to a non-empty slice.
     #[inline]
-    #[must_use]
     pub fn try_new_mut(slice: &mut [T]) -> Result<&mut NonEmpty<T>, &mut [T]> {
-        if slice.len() > 0 {
+        if !slice.is_empty() {
             // SAFETY: slice is not empty
             Ok(unsafe { NonEmpty::new_unchecked_mut(slice) })
         } else {
@@ -317,11 +315,6 @@ impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmpty<T> {
     fn eq(&self, other: &[U]) -> bool {
         self.as_slice().eq(other)
     }
-
-    #[inline]
-    fn ne(&self, other: &[U]) -> bool {
-        self.as_slice().ne(other)
-    }
 }
 
 impl<T: PartialEq<U>, U> PartialEq<U> for NonEmpty<T> {
@@ -329,11 +322,6 @@ impl<T: PartialEq<U>, U> PartialEq<U> for NonEmpty<T> {
     fn eq(&self, other: &U) -> bool {
         self.eq(slice::from_ref(other))
     }
-
-    #[inline]
-    fn ne(&self, other: &U) -> bool {
-        self.ne(slice::from_ref(other))
-    }
 }
 
 impl<T: PartialEq> PartialEq for NonEmpty<T> {
@@ -341,11 +329,6 @@ impl<T: PartialEq> PartialEq for NonEmpty<T> {
     fn eq(&self, other: &Self) -> bool {
         self.eq(other.as_slice())
     }
-
-    #[inline]
-    fn ne(&self, other: &Self) -> bool {
-        self.ne(other.as_slice())
-    }
 }
 
 impl<T: Eq> Eq for NonEmpty<T> {}
@@ -0,0 +1,79 @@
+use std::{fmt, ops::Range};
+
+/// A range of byte offsets into a pattern.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[inline]
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Get an empty span at `offset`.
+    #[inline]
+    #[must_use]
+    pub const fn at(offset: usize) -> Span {
+        Span::new(offset, offset)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+
+    /// Get the smallest span containing both `self` and `other`.
+    #[inline]
+    #[must_use]
+    pub const fn join(self, other: Span) -> Span {
+        Span {
+            start: if self.start < other.start {
+                self.start
+            } else {
+                other.start
+            },
+            end: if self.end > other.end {
+                self.end
+            } else {
+                other.end
+            },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_range(self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl From<Range<usize>> for Span {
+    #[inline]
+    fn from(value: Range<usize>) -> Self {
+        Span::new(value.start, value.end)
+    }
+}
+
+impl From<Span> for Range<usize> {
+    #[inline]
+    fn from(value: Span) -> Self {
+        value.to_range()
+    }
+}
+
+impl fmt::Debug for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
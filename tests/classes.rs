@@ -0,0 +1,252 @@
+use bumpalo::Bump;
+use regram::{
+    ast::{
+        canonicalize_ranges, complement_ranges, difference_ranges, intersect_ranges,
+        symmetric_difference_ranges, Ast, ByteRange, CharClass, CharRange, Class,
+    },
+    exec::Backtracker,
+    parse::parse,
+    util::slice::NonEmpty,
+};
+
+/// A small deterministic generator, so that failures can be reproduced.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+
+    fn bytes(&mut self) -> Vec<ByteRange> {
+        (0..self.next() % 5)
+            .map(|_| {
+                let a = self.next() as u8;
+                let b = a.saturating_add(self.next() as u8 % 32);
+                ByteRange::new(a, b)
+            })
+            .collect()
+    }
+
+    fn chars(&mut self) -> Vec<CharRange> {
+        let scalar = |n: u32| char::from_u32(n).unwrap_or('\u{E000}');
+
+        (0..self.next() % 5)
+            .map(|_| {
+                let a = self.next() % 0x11_0000;
+                let b = (a + self.next() % 0x1000).min(0x10_FFFF);
+                let (a, b) = (scalar(a), scalar(b));
+                CharRange::new(a.min(b), a.max(b))
+            })
+            .collect()
+    }
+}
+
+fn has_byte(ranges: &[ByteRange], byte: u8) -> bool {
+    ranges
+        .iter()
+        .any(|range| range.to_inclusive().contains(&byte))
+}
+
+fn has_char(ranges: &[CharRange], c: char) -> bool {
+    ranges.iter().any(|range| range.to_inclusive().contains(&c))
+}
+
+fn is_canonical_bytes(ranges: &[ByteRange]) -> bool {
+    ranges.iter().all(|range| range.start <= range.end)
+        && ranges
+            .windows(2)
+            .all(|pair| u16::from(pair[0].end) + 1 < u16::from(pair[1].start))
+}
+
+fn is_canonical_chars(ranges: &[CharRange]) -> bool {
+    ranges.iter().all(|range| range.start <= range.end)
+        && ranges.windows(2).all(|pair| {
+            // Ranges on either side of the surrogates are adjacent.
+            let next = match pair[0].end {
+                '\u{D7FF}' => 0xE000,
+                end => u32::from(end) + 1,
+            };
+
+            next < u32::from(pair[1].start)
+        })
+}
+
+fn chars() -> impl Iterator<Item = char> {
+    (0..0x11_0000)
+        .step_by(61)
+        .chain([0xD7FF, 0xE000, 0x10_FFFF])
+        .filter_map(char::from_u32)
+}
+
+#[test]
+fn byte_set_algebra() {
+    let mut rng = Rng(1);
+
+    for _ in 0..500 {
+        let (a, b) = (rng.bytes(), rng.bytes());
+
+        let mut union = [&a[..], &b[..]].concat();
+        canonicalize_ranges(&mut union);
+        let complement = complement_ranges(&a);
+        let intersection = intersect_ranges(&a, &b);
+        let difference = difference_ranges(&a, &b);
+        let symmetric = symmetric_difference_ranges(&a, &b);
+
+        for ranges in [&union, &complement, &intersection, &difference, &symmetric] {
+            assert!(is_canonical_bytes(ranges), "{ranges:?}");
+        }
+
+        for byte in 0..=u8::MAX {
+            let (in_a, in_b) = (has_byte(&a, byte), has_byte(&b, byte));
+
+            assert_eq!(has_byte(&union, byte), in_a || in_b);
+            assert_eq!(has_byte(&complement, byte), !in_a);
+            assert_eq!(has_byte(&intersection, byte), in_a && in_b);
+            assert_eq!(has_byte(&difference, byte), in_a && !in_b);
+            assert_eq!(has_byte(&symmetric, byte), in_a != in_b);
+        }
+    }
+}
+
+#[test]
+fn char_set_algebra() {
+    let mut rng = Rng(2);
+
+    for _ in 0..100 {
+        let (a, b) = (rng.chars(), rng.chars());
+
+        let complement = complement_ranges(&a);
+        let intersection = intersect_ranges(&a, &b);
+        let difference = difference_ranges(&a, &b);
+        let symmetric = symmetric_difference_ranges(&a, &b);
+
+        for ranges in [&complement, &intersection, &difference, &symmetric] {
+            assert!(is_canonical_chars(ranges), "{ranges:?}");
+        }
+
+        for c in chars() {
+            let (in_a, in_b) = (has_char(&a, c), has_char(&b, c));
+
+            assert_eq!(has_char(&complement, c), !in_a);
+            assert_eq!(has_char(&intersection, c), in_a && in_b);
+            assert_eq!(has_char(&difference, c), in_a && !in_b);
+            assert_eq!(has_char(&symmetric, c), in_a != in_b);
+        }
+    }
+}
+
+#[test]
+fn complement_spans_the_surrogates() {
+    let ranges = complement_ranges(&[CharRange::new('a', 'z')]);
+    assert_eq!(
+        ranges,
+        [CharRange::new('\0', '`'), CharRange::new('{', char::MAX)]
+    );
+
+    assert!(complement_ranges(&[
+        CharRange::new('\0', '\u{D7FF}'),
+        CharRange::new('\u{E000}', char::MAX)
+    ])
+    .is_empty());
+    assert_eq!(
+        complement_ranges::<ByteRange>(&[]),
+        [ByteRange::new(0, u8::MAX)]
+    );
+}
+
+#[test]
+fn class_methods() {
+    let bump = Bump::new();
+    let mut a = [ByteRange::new(b'a', b'm')];
+    let mut b = [ByteRange::new(b'h', b'z')];
+    let a = Class {
+        ranges: NonEmpty::new_mut(&mut a),
+    };
+    let b = Class {
+        ranges: NonEmpty::new_mut(&mut b),
+    };
+
+    let union = a.union(&b, &bump);
+    assert_eq!(union.as_slice(), [ByteRange::new(b'a', b'z')]);
+
+    let intersection = a.intersection(&b, &bump).unwrap();
+    assert_eq!(intersection.as_slice(), [ByteRange::new(b'h', b'm')]);
+
+    let difference = a.difference(&b, &bump).unwrap();
+    assert_eq!(difference.as_slice(), [ByteRange::new(b'a', b'g')]);
+
+    let symmetric = a.symmetric_difference(&b, &bump).unwrap();
+    assert_eq!(
+        symmetric.as_slice(),
+        [ByteRange::new(b'a', b'g'), ByteRange::new(b'n', b'z')]
+    );
+
+    assert!(a.difference(&union, &bump).is_none());
+    assert!(union.complement(&bump).unwrap().contains(b'{'));
+    assert!(!union.complement(&bump).unwrap().contains(b'q'));
+}
+
+#[test]
+fn char_class_methods() {
+    let bump = Bump::new();
+    let mut a = [CharRange::new('α', 'ω')];
+    let mut b = [CharRange::new('a', 'z'), CharRange::new('λ', 'λ')];
+    let a = CharClass {
+        ranges: NonEmpty::new_mut(&mut a),
+    };
+    let b = CharClass {
+        ranges: NonEmpty::new_mut(&mut b),
+    };
+
+    let intersection = a.intersection(&b, &bump).unwrap();
+    assert_eq!(intersection.as_slice(), [CharRange::new('λ', 'λ')]);
+
+    let difference = a.difference(&b, &bump).unwrap();
+    assert!(difference.contains('α'));
+    assert!(!difference.contains('λ'));
+
+    let union = a.union(&b, &bump);
+    assert!(union.contains('q') && union.contains('λ'));
+
+    let complement = a.complement(&bump).unwrap();
+    assert!(complement.contains('a') && !complement.contains('μ'));
+    assert!(a.symmetric_difference(&a, &bump).is_none());
+}
+
+#[test]
+fn parsed_set_operations() {
+    let bump = Bump::new();
+    let cases: &[(&str, &str, &str)] = &[
+        ("[a-z&&[^aeiou]]", "bcdxz", "aeiou0"),
+        ("[a-z--[aeiou]]", "bcdxz", "aeiou0"),
+        ("[a-f~~d-k]", "abcghk", "defl"),
+        ("[\\w&&\\d]", "059", "a_"),
+        ("[[a-c][x-z]&&[b-y]]", "bcxy", "adwz"),
+        ("[a-z&&b-y&&c-x]", "cx", "by"),
+    ];
+
+    for &(pattern, matches, rejects) in cases {
+        let ast = parse(pattern.as_bytes(), &bump).unwrap();
+        assert!(matches!(ast, Ast::Class(_)), "{pattern:?} is {ast:?}");
+
+        let backtracker = Backtracker::new(&ast).unwrap();
+
+        for c in matches.chars() {
+            assert!(
+                backtracker.is_full_match(c.to_string().as_bytes()),
+                "{pattern:?} {c:?}"
+            );
+        }
+
+        for c in rejects.chars() {
+            assert!(
+                !backtracker.is_full_match(c.to_string().as_bytes()),
+                "{pattern:?} {c:?}"
+            );
+        }
+    }
+}
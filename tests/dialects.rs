@@ -0,0 +1,303 @@
+use bumpalo::Bump;
+use regram::{
+    exec::Backtracker,
+    parse::{parse_with, ParseErrorKind, Syntax},
+};
+
+/// A pattern that `syntax` accepts, with inputs it fully matches and inputs it does not.
+struct Accept {
+    syntax: Syntax,
+    pattern: &'static str,
+    matches: &'static [&'static str],
+    rejects: &'static [&'static str],
+}
+
+const ACCEPT: &[Accept] = &[
+    // Set operations and nested classes.
+    Accept {
+        syntax: Syntax::DEFAULT,
+        pattern: "[a-z&&[^aeiou]]",
+        matches: &["b", "z"],
+        rejects: &["a", "&", "["],
+    },
+    Accept {
+        syntax: Syntax::DEFAULT,
+        pattern: "[a-c--b]",
+        matches: &["a", "c"],
+        rejects: &["b", "-"],
+    },
+    Accept {
+        syntax: Syntax::DEFAULT,
+        pattern: "[a-c~~b-d]",
+        matches: &["a", "d"],
+        rejects: &["b", "c"],
+    },
+    Accept {
+        syntax: Syntax::RUST,
+        pattern: "[[:digit:]&&[^5]]",
+        matches: &["0", "9"],
+        rejects: &["5", "a"],
+    },
+    // PCRE takes those as literals.
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[[]",
+        matches: &["["],
+        rejects: &["]"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[]a]",
+        matches: &["]", "a"],
+        rejects: &["b"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[^]a]",
+        matches: &["b"],
+        rejects: &["]", "a"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[+--]",
+        matches: &["+", ",", "-"],
+        rejects: &["."],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[a&&b]",
+        matches: &["a", "&", "b"],
+        rejects: &["c"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "[a~~]",
+        matches: &["a", "~"],
+        rejects: &["b"],
+    },
+    Accept {
+        syntax: Syntax::RE2,
+        pattern: "[]a]",
+        matches: &["]", "a"],
+        rejects: &["b"],
+    },
+    // ECMAScript has empty classes, and a `]` always closes one.
+    Accept {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: "[]",
+        matches: &[],
+        rejects: &["", "a", "]"],
+    },
+    Accept {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: "[^]",
+        matches: &["a", "]", "\n"],
+        rejects: &[""],
+    },
+    Accept {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: "[]a]",
+        matches: &[],
+        rejects: &["a]", "a", "]"],
+    },
+    Accept {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: "[[]",
+        matches: &["["],
+        rejects: &["]"],
+    },
+    Accept {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: r"\u0041",
+        matches: &["A"],
+        rejects: &["u0041"],
+    },
+    // POSIX bracket expressions.
+    Accept {
+        syntax: Syntax::POSIX_EXTENDED,
+        pattern: "[]a]",
+        matches: &["]", "a"],
+        rejects: &["b"],
+    },
+    Accept {
+        syntax: Syntax::POSIX_EXTENDED,
+        pattern: r"[\d]",
+        matches: &["\\", "d"],
+        rejects: &["0"],
+    },
+    Accept {
+        syntax: Syntax::POSIX_EXTENDED,
+        pattern: "[[.-.]a]",
+        matches: &["-", "a"],
+        rejects: &["."],
+    },
+    Accept {
+        syntax: Syntax::POSIX_BASIC,
+        pattern: "[a&&b]",
+        matches: &["&"],
+        rejects: &["c"],
+    },
+    // Escapes that only some dialects have.
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: r"\012",
+        matches: &["\n"],
+        rejects: &["012"],
+    },
+    Accept {
+        syntax: Syntax::RE2,
+        pattern: r"\Qa.b\E",
+        matches: &["a.b"],
+        rejects: &["axb"],
+    },
+    Accept {
+        syntax: Syntax::RUST,
+        pattern: r"\Aa\z",
+        matches: &["a"],
+        rejects: &["a\n"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: r"\Aa\Z\n?",
+        matches: &["a", "a\n"],
+        rejects: &["a\n\n"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "a{,",
+        matches: &["a{,"],
+        rejects: &["a"],
+    },
+    Accept {
+        syntax: Syntax::PCRE,
+        pattern: "a*+b",
+        matches: &["aab"],
+        rejects: &["aa"],
+    },
+];
+
+/// A pattern that `syntax` rejects.
+struct Reject {
+    syntax: Syntax,
+    pattern: &'static str,
+    kind: ParseErrorKind,
+}
+
+const REJECT: &[Reject] = &[
+    Reject {
+        syntax: Syntax::DEFAULT,
+        pattern: "[]",
+        kind: ParseErrorKind::EmptyClass,
+    },
+    Reject {
+        syntax: Syntax::DEFAULT,
+        pattern: "[[]",
+        kind: ParseErrorKind::EmptyClass,
+    },
+    Reject {
+        syntax: Syntax::RUST,
+        pattern: "[]",
+        kind: ParseErrorKind::UnclosedClass,
+    },
+    Reject {
+        syntax: Syntax::PCRE,
+        pattern: "[]",
+        kind: ParseErrorKind::UnclosedClass,
+    },
+    Reject {
+        syntax: Syntax::POSIX_BASIC,
+        pattern: "[]",
+        kind: ParseErrorKind::UnclosedClass,
+    },
+    Reject {
+        syntax: Syntax::PCRE,
+        pattern: "[b-a]",
+        kind: ParseErrorKind::InvalidClassRange,
+    },
+    Reject {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: "a*+",
+        kind: ParseErrorKind::MissingRepeatOperand,
+    },
+    Reject {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: r"\Aa",
+        kind: ParseErrorKind::DisabledEscape,
+    },
+    Reject {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: r"a\z",
+        kind: ParseErrorKind::DisabledEscape,
+    },
+    Reject {
+        syntax: Syntax::ECMASCRIPT,
+        pattern: r"a\Z",
+        kind: ParseErrorKind::DisabledEscape,
+    },
+    Reject {
+        syntax: Syntax::RUST,
+        pattern: r"a\Z",
+        kind: ParseErrorKind::DisabledEscape,
+    },
+    Reject {
+        syntax: Syntax::RE2,
+        pattern: r"a\Z",
+        kind: ParseErrorKind::DisabledEscape,
+    },
+];
+
+#[test]
+fn accepted() {
+    for case in ACCEPT {
+        let bump = Bump::new();
+        let ast = parse_with(case.pattern.as_bytes(), case.syntax, &bump)
+            .unwrap_or_else(|err| panic!("{:?} in {:?}: {err}", case.pattern, case.syntax));
+        let backtracker = Backtracker::new(&ast).unwrap();
+
+        for input in case.matches {
+            assert!(
+                backtracker.is_full_match(input.as_bytes()),
+                "{:?} in {:?} should match {input:?}",
+                case.pattern,
+                case.syntax.dialect,
+            );
+        }
+
+        for input in case.rejects {
+            assert!(
+                !backtracker.is_full_match(input.as_bytes()),
+                "{:?} in {:?} should not match {input:?}",
+                case.pattern,
+                case.syntax.dialect,
+            );
+        }
+    }
+}
+
+#[test]
+fn rejected() {
+    for case in REJECT {
+        let bump = Bump::new();
+        let err = parse_with(case.pattern.as_bytes(), case.syntax, &bump)
+            .map(|ast| format!("{ast:?}"))
+            .expect_err(case.pattern);
+
+        assert_eq!(
+            err.kind, case.kind,
+            "{:?} in {:?}",
+            case.pattern, case.syntax
+        );
+    }
+}
+
+#[test]
+fn posix_dialects_use_posix_presets() {
+    assert_eq!(
+        Syntax::from(regram::parse::Dialect::PosixBasic),
+        Syntax::POSIX_BASIC
+    );
+    assert_eq!(
+        Syntax::from(regram::parse::Dialect::PosixExtended),
+        Syntax::POSIX_EXTENDED
+    );
+}
@@ -0,0 +1,57 @@
+use bumpalo::Bump;
+use regram::{
+    ast::RepeatKind,
+    parse::{parse, ParseErrorKind},
+};
+
+/// Parse `pattern`, which must fail, returning the kind and byte range of the error.
+fn error(pattern: &str) -> (ParseErrorKind, usize, usize) {
+    let bump = Bump::new();
+    let err = parse(pattern.as_bytes(), &bump).unwrap_err();
+
+    (err.kind, err.span.start, err.span.end)
+}
+
+#[test]
+fn spans() {
+    assert_eq!(error("(a"), (ParseErrorKind::UnclosedGroup, 0, 1));
+    assert_eq!(error("ab)"), (ParseErrorKind::UnopenedGroup, 2, 3));
+    assert_eq!(error("a**"), (ParseErrorKind::MissingRepeatOperand, 2, 3));
+    assert_eq!(
+        error("a{2,1}"),
+        (
+            ParseErrorKind::InvalidRepeatBounds(RepeatKind::Bounded(2, 1)),
+            1,
+            6
+        )
+    );
+    assert_eq!(error("x\\q"), (ParseErrorKind::UnrecognizedEscape, 1, 3));
+    assert_eq!(error("(?z)"), (ParseErrorKind::UnrecognizedFlag, 2, 3));
+    assert_eq!(
+        error("[[:foo:]]"),
+        (ParseErrorKind::UnrecognizedClass, 1, 8)
+    );
+    assert_eq!(
+        error("(?<n>a)(?<n>b)"),
+        (ParseErrorKind::DuplicateGroupName, 10, 11)
+    );
+    assert_eq!(
+        error("a{1001}"),
+        (ParseErrorKind::RepeatLimitExceeded(1000), 1, 7)
+    );
+}
+
+#[test]
+fn diagnostic() {
+    let bump = Bump::new();
+    let pattern = b"a{2,1}";
+    let err = parse(pattern, &bump).unwrap_err();
+
+    assert_eq!(
+        err.diagnostic(pattern).to_string(),
+        "error: invalid repetition bounds: minimum 2 is greater than maximum 1\n  \
+         |\n\
+         1 | a{2,1}\n  \
+         |  ^^^^^",
+    );
+}
@@ -0,0 +1,152 @@
+use std::ops::Range;
+
+use bumpalo::Bump;
+use regram::{
+    exec::{Backtracker, PikeVm, Unsupported},
+    parse::{parse, parse_with, Dialect, MatchKind, Syntax},
+};
+
+#[test]
+fn pike_vm_rejects_large_programs() {
+    let bump = Bump::new();
+    let ast = parse(b"((a{1000}){1000}){1000}", &bump).unwrap();
+
+    assert_eq!(PikeVm::new(&ast).unwrap_err(), Unsupported::TooLarge);
+
+    let ast = parse(b"(a{1000}){100}", &bump).unwrap();
+    assert!(PikeVm::new(&ast).is_ok());
+}
+
+const PATTERNS: &[&str] = &[
+    "a",
+    "abc",
+    "a|b|ab",
+    "ab|a",
+    "a*",
+    "a*?",
+    "a+b",
+    "a+?b",
+    "(a|ab)(c|bcd)(d*)",
+    "(a+)(b+)?",
+    "(a*)*",
+    "(a*)+b",
+    "(a|b)*?c",
+    "x(a{2,3})y",
+    "(?:ab){2}",
+    "a{2,}?",
+    "[a-c]+d?",
+    "[^a]+",
+    "^a",
+    "a$",
+    "^$",
+    "\\bab\\b",
+    "\\Ba",
+    "(?m)^b",
+    "(?s).+",
+    ".+",
+    "(?i)AB",
+    "(?u)é+",
+    "(?u)[α-ω]+",
+    "(?u)\\w+",
+    "((a)|(b))+",
+    "(?<x>a)(?<y>b)?",
+    "",
+];
+
+const HAYSTACKS: &[&str] = &[
+    "", "a", "b", "ab", "aab", "abab", "abcd", "xaay", "xaaay", "ba ab", "a\nb", "AbaB", "éé",
+    "λx", "cccd", "aaabbb", "bbc",
+];
+
+#[test]
+fn pike_vm_agrees_with_backtracker() {
+    let bump = Bump::new();
+
+    for kind in [MatchKind::LeftmostFirst, MatchKind::LeftmostLongest] {
+        for pattern in PATTERNS {
+            let ast = parse(pattern.as_bytes(), &bump).unwrap();
+            let backtracker = Backtracker::new_with(&ast, kind).unwrap();
+            let pike = PikeVm::new_with(&ast, kind).unwrap();
+
+            for haystack in HAYSTACKS {
+                assert_eq!(
+                    pike.captures(haystack.as_bytes()),
+                    backtracker.captures(haystack.as_bytes()),
+                    "{pattern:?} on {haystack:?} ({kind:?})",
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn posix_dialects_find_leftmost_longest_matches() {
+    let bump = Bump::new();
+    let kind = Dialect::PosixExtended.match_kind();
+    let cases: &[(&str, &str, Option<Range<usize>>)] = &[
+        ("a|ab", "abc", Some(0..2)),
+        ("ab|a", "abc", Some(0..2)),
+        ("(a|ab)(c|bcd)", "abcd", Some(0..4)),
+        ("a*", "baaa", Some(0..0)),
+        ("x*|b", "bxx", Some(0..1)),
+        ("(a|b)*c|a", "abab", Some(0..1)),
+        ("c", "ab", None),
+    ];
+
+    for (pattern, haystack, expected) in cases {
+        let ast = parse_with(pattern.as_bytes(), Syntax::POSIX_EXTENDED, &bump).unwrap();
+        let backtracker = Backtracker::new_with(&ast, kind).unwrap();
+        let pike = PikeVm::new_with(&ast, kind).unwrap();
+
+        assert_eq!(&pike.find(haystack.as_bytes()), expected, "{pattern:?}");
+        assert_eq!(
+            &backtracker.find(haystack.as_bytes()),
+            expected,
+            "{pattern:?}"
+        );
+    }
+
+    let ast = parse(b"a|ab", &bump).unwrap();
+    assert_eq!(PikeVm::new(&ast).unwrap().find(b"abc"), Some(0..1));
+    assert_eq!(Backtracker::new(&ast).unwrap().find(b"abc"), Some(0..1));
+}
+
+#[test]
+fn pike_vm_rejects_backtracking_nodes() {
+    let bump = Bump::new();
+    let cases: &[(&str, Unsupported)] = &[
+        ("(?=a)", Unsupported::Lookaround),
+        ("(?>a)", Unsupported::AtomicGroup),
+        ("a*+", Unsupported::PossessiveRepeat),
+        ("(a)\\1", Unsupported::Backreference),
+    ];
+
+    for &(pattern, unsupported) in cases {
+        let ast = parse(pattern.as_bytes(), &bump).unwrap();
+        assert_eq!(PikeVm::new(&ast).unwrap_err(), unsupported, "{pattern:?}");
+    }
+}
+
+#[test]
+fn backtracker_handles_long_inputs() {
+    let bump = Bump::new();
+    let ast = parse(b"(?:a|b)*c", &bump).unwrap();
+    let backtracker = Backtracker::new(&ast).unwrap();
+
+    let mut haystack = b"ab".repeat(1 << 19);
+    assert!(!backtracker.is_full_match(&haystack));
+
+    haystack.push(b'c');
+    assert!(backtracker.is_full_match(&haystack));
+    assert_eq!(backtracker.find(&haystack), Some(0..haystack.len()));
+
+    let ast = parse(b"((?:x{2}|y)+?)(?=z)", &bump).unwrap();
+    let backtracker = Backtracker::new(&ast).unwrap();
+    let haystack = [&b"xxy".repeat(1 << 18)[..], b"z"].concat();
+    let end = haystack.len() - 1;
+
+    assert_eq!(
+        backtracker.captures(&haystack),
+        Some(vec![Some(0..end), Some(0..end)])
+    );
+}
@@ -0,0 +1,135 @@
+use bumpalo::Bump;
+use regram::{
+    exec::{Backtracker, PikeVm},
+    generate::Generator,
+    parse::parse,
+};
+
+/// Generate strings for `pattern` from a range of seeds, checking that each is matched in
+/// full.
+fn generated(pattern: &str) -> Vec<Vec<u8>> {
+    let bump = Bump::new();
+    let ast = parse(pattern.as_bytes(), &bump).unwrap();
+    let backtracker = Backtracker::new(&ast).unwrap();
+    // Where it can, an anchored Pike VM checks the strings independently.
+    let anchored = format!("\\A(?:{pattern})\\z");
+    let anchored = parse(anchored.as_bytes(), &bump).unwrap();
+    let pike = PikeVm::new(&anchored).ok();
+
+    let mut out = Vec::new();
+
+    for seed in 0..20 {
+        let mut generator = Generator::new(&ast, seed).unwrap();
+
+        for _ in 0..10 {
+            let string = generator
+                .generate()
+                .unwrap_or_else(|| panic!("nothing generated for {pattern:?}"));
+
+            assert!(
+                backtracker.is_full_match(&string),
+                "{pattern:?} generated {string:?}"
+            );
+
+            if let Some(pike) = &pike {
+                assert!(pike.is_match(&string), "{pattern:?} generated {string:?}");
+            }
+
+            out.push(string);
+        }
+    }
+
+    out
+}
+
+#[test]
+fn generated_strings_match() {
+    let patterns = [
+        "abc",
+        "a|bc|def",
+        "a*b+c?",
+        "(ab){2,4}",
+        "[a-f0-9]{8}",
+        "[^a-y]",
+        "(?i)hello",
+        "(?u)[α-ω]+",
+        "(?u)\\w\\d\\s",
+        "(?u).{3}",
+        "(?s).",
+        "^a$",
+        "\\bfoo\\b",
+        "(a|b)(c|d)\\1\\2",
+        "(?<q>['\"])x\\k<q>",
+        "(?i)(ab)\\1",
+        "(?iu)(é)\\1",
+        "a(?=b)b",
+        "(?!ab)[ab]{2}",
+        "(?<=a)b|ab",
+        "(?>a+)b",
+        "a*+b",
+        "[a-z&&[^aeiou]]+",
+        "",
+    ];
+
+    for pattern in patterns {
+        generated(pattern);
+    }
+}
+
+#[test]
+fn generated_strings_are_utf8_in_unicode_mode() {
+    for pattern in ["(?u).{4}", "(?u)[^a]{4}", "(?u)\\W+", "(?u)\\p{Greek}"] {
+        for string in generated(pattern) {
+            assert!(
+                std::str::from_utf8(&string).is_ok(),
+                "{pattern:?} {string:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn generation_is_deterministic() {
+    let bump = Bump::new();
+    let ast = parse(b"[a-z]{1,8}(x|y)*", &bump).unwrap();
+
+    let run = |seed| {
+        let mut generator = Generator::new(&ast, seed).unwrap();
+        (0..10).map(|_| generator.generate()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(run(7), run(7));
+    assert_ne!(run(7), run(8));
+}
+
+#[test]
+fn unsatisfiable_patterns_generate_nothing() {
+    let bump = Bump::new();
+
+    for pattern in ["a(?=b)c", "$a", "a\\bb", "(?!a)a", "(?>a*)a", "a*+a"] {
+        let ast = parse(pattern.as_bytes(), &bump).unwrap();
+        let mut generator = Generator::new(&ast, 1).unwrap();
+
+        assert_eq!(generator.generate(), None, "{pattern:?}");
+    }
+}
+
+#[test]
+fn generated_strings_are_bounded() {
+    let bump = Bump::new();
+
+    let ast = parse(b"(?:a{100}){100}", &bump).unwrap();
+    let mut generator = Generator::new(&ast, 1).unwrap();
+    assert_eq!(generator.generate(), Some(b"a".repeat(10_000)));
+
+    for pattern in [
+        &b"(?:(?:a{1000}){1000}){1000}"[..],
+        b"(?:(?:\\b{1000}){1000}){1000}x",
+        b"(a{1000})\\1{1000}",
+    ] {
+        let ast = parse(pattern, &bump).unwrap();
+        let mut generator = Generator::new(&ast, 1).unwrap();
+
+        assert_eq!(generator.generate(), None, "{pattern:?}");
+    }
+}
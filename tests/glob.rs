@@ -0,0 +1,119 @@
+use bumpalo::Bump;
+use regram::{
+    exec::Backtracker,
+    parse::{parse_gitignore, parse_glob, ParseErrorKind},
+};
+
+fn glob(pattern: &str, path: &str) -> bool {
+    let bump = Bump::new();
+    let ast = parse_glob(pattern.as_bytes(), &bump).unwrap();
+
+    Backtracker::new(&ast)
+        .unwrap()
+        .is_full_match(path.as_bytes())
+}
+
+/// Check whether the `.gitignore` line `line` applies to `path`, and whether it is negated.
+fn gitignore(line: &str, path: &str) -> Option<bool> {
+    let bump = Bump::new();
+    let pattern = parse_gitignore(line.as_bytes(), &bump).unwrap()?;
+
+    Backtracker::new(&pattern.ast)
+        .unwrap()
+        .is_full_match(path.as_bytes())
+        .then_some(pattern.negated)
+}
+
+#[test]
+fn stars() {
+    assert!(glob("*.rs", "main.rs"));
+    assert!(!glob("*.rs", "src/main.rs"));
+    assert!(glob("src/**/*.rs", "src/main.rs"));
+    assert!(glob("src/**/*.rs", "src/parse/glob.rs"));
+    assert!(glob("**", "a/b/c"));
+    assert!(!glob("a**b", "a/b"));
+}
+
+#[test]
+fn question_mark_matches_a_scalar_value() {
+    assert!(glob("caf?", "café"));
+    assert!(glob("???", "日本語"));
+    assert!(!glob("a?b", "a/b"));
+    assert!(!glob("?", ""));
+}
+
+#[test]
+fn classes() {
+    assert!(glob("[a-c]x", "bx"));
+    assert!(!glob("[!a-c]x", "bx"));
+    assert!(glob("[]]", "]"));
+    assert!(glob("[[:digit:]]", "7"));
+    assert!(!glob("a[/]b", "a/b"));
+}
+
+#[test]
+fn classes_match_scalar_values() {
+    assert!(glob("[é]", "é"));
+    assert!(!glob("[é]", "e"));
+    assert!(glob("caf[!x]", "café"));
+    assert!(glob("[!x]", "日"));
+    assert!(!glob("[!x]", "x"));
+    assert!(!glob("[!x]", "/"));
+    assert!(glob("[α-ω]x", "λx"));
+    assert!(!glob("[α-ω]x", "Λx"));
+    assert!(glob("[!α-ω]", "Λ"));
+    assert!(glob("[\\日]", "日"));
+
+    let bump = Bump::new();
+    let err = parse_glob(b"[\xff]", &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NonAsciiClass);
+    assert_eq!((err.span.start, err.span.end), (1, 2));
+}
+
+#[test]
+fn braces() {
+    assert!(glob("*.{rs,toml}", "Cargo.toml"));
+    assert!(!glob("*.{rs,toml}", "README.md"));
+    assert!(glob("{a,{b,c}}d", "cd"));
+    assert!(glob("a,b", "a,b"));
+}
+
+#[test]
+fn unclosed_brackets_are_literals() {
+    assert!(glob("a[b", "a[b"));
+    assert!(glob("[!", "[!"));
+    assert!(glob("a{b", "a{b"));
+    assert!(glob("{a,{b}", "{a,b"));
+    assert!(glob("{a{b,c}", "{ac"));
+    assert!(glob("{{{x", "{{{x"));
+}
+
+#[test]
+fn errors() {
+    let bump = Bump::new();
+
+    let err = parse_glob(b"a\\", &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::DanglingEscape);
+    assert_eq!((err.span.start, err.span.end), (1, 2));
+
+    let err = parse_glob(b"[z-a]", &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidClassRange);
+    assert_eq!((err.span.start, err.span.end), (1, 4));
+}
+
+#[test]
+fn gitignore_lines() {
+    assert_eq!(gitignore("# comment", "x"), None);
+    assert_eq!(gitignore("target", "target"), Some(false));
+    assert_eq!(gitignore("target", "a/b/target"), Some(false));
+    assert_eq!(gitignore("/target", "a/target"), None);
+    assert_eq!(gitignore("/target", "target"), Some(false));
+    assert_eq!(gitignore("doc/*.md", "doc/a.md"), Some(false));
+    assert_eq!(gitignore("doc/*.md", "x/doc/a.md"), None);
+    assert_eq!(gitignore("!keep.log", "logs/keep.log"), Some(true));
+    assert_eq!(gitignore("{a,b}", "{a,b}"), Some(false));
+    assert_eq!(gitignore("trailing\\ ", "trailing "), Some(false));
+
+    let bump = Bump::new();
+    assert!(parse_gitignore(b"build/", &bump).unwrap().unwrap().dir_only);
+}
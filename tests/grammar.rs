@@ -0,0 +1,250 @@
+use bumpalo::Bump;
+use regram::{
+    ast::RepeatKind,
+    exec::Backtracker,
+    parse::{
+        parse_abnf, parse_abnf_with, parse_ebnf, parse_ebnf_with, Grammar, Limits, ParseErrorKind,
+    },
+};
+
+/// Check that the rule `name` of `grammar` fully matches each of `matches` and none of
+/// `rejects`.
+fn check(grammar: &Grammar, name: &str, matches: &[&str], rejects: &[&str]) {
+    let bump = Bump::new();
+    let ast = grammar.expand(name, &bump).unwrap().unwrap();
+    let backtracker = Backtracker::new(&ast).unwrap();
+
+    for input in matches {
+        assert!(
+            backtracker.is_full_match(input.as_bytes()),
+            "{name} {input:?}"
+        );
+    }
+
+    for input in rejects {
+        assert!(
+            !backtracker.is_full_match(input.as_bytes()),
+            "{name} {input:?}"
+        );
+    }
+}
+
+#[test]
+fn abnf_rules() {
+    let grammar = parse_abnf(
+        b"greeting = \"hi\" / %s\"Yo\"\r\n\
+          hex = 1*2HEXDIG ; comment\r\n\
+          upper = %x41-5A\n\
+          crlf = %d13.10\n\
+          word = ALPHA *(ALPHA / DIGIT / \"-\")\n\
+          list = word *(\",\" SP word)\n\
+          opt = [\"+\"] 3DIGIT\n\
+          opt =/ \"none\"\n\
+          snow = %x2603\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        grammar.names().collect::<Vec<_>>(),
+        ["greeting", "hex", "upper", "crlf", "word", "list", "opt", "snow"]
+    );
+
+    check(&grammar, "greeting", &["hi", "HI", "Yo"], &["yo", "h"]);
+    check(&grammar, "HEX", &["a", "F0"], &["", "abc", "g"]);
+    check(&grammar, "upper", &["A", "Z"], &["a", "["]);
+    check(&grammar, "crlf", &["\r\n"], &["\n"]);
+    check(&grammar, "list", &["a", "a1, b-2"], &["a,b", "1a"]);
+    check(&grammar, "opt", &["123", "+123", "none"], &["+12", "++123"]);
+    check(&grammar, "snow", &["\u{2603}"], &["\u{2602}"]);
+
+    assert!(grammar.expand("missing", &Bump::new()).is_none());
+}
+
+#[test]
+fn abnf_numeric_values() {
+    let grammar = parse_abnf(
+        b"high = %x80-FF\n\
+          latin = %xE9\n\
+          mixed = %x41.E9.2603\n\
+          wide = %xF0-101\n\
+          utf8 = %xC3.A9\n",
+    )
+    .unwrap();
+
+    let bump = Bump::new();
+    let full_match = |name, input: &[u8]| {
+        let ast = grammar.expand(name, &bump).unwrap().unwrap();
+        Backtracker::new(&ast).unwrap().is_full_match(input)
+    };
+
+    assert!(full_match("high", b"\x80"));
+    assert!(full_match("high", b"\xff"));
+    assert!(!full_match("high", "é".as_bytes()));
+    assert!(!full_match("high", b"\x7f"));
+    assert!(full_match("latin", b"\xe9"));
+    assert!(!full_match("latin", "é".as_bytes()));
+    assert!(full_match(
+        "mixed",
+        &[&b"A\xe9"[..], "\u{2603}".as_bytes()].concat()
+    ));
+    assert!(full_match("wide", b"\xf0"));
+    assert!(full_match("wide", "\u{100}".as_bytes()));
+    assert!(full_match("wide", "\u{101}".as_bytes()));
+    assert!(!full_match("wide", "\u{102}".as_bytes()));
+    assert!(full_match("utf8", "é".as_bytes()));
+}
+
+#[test]
+fn abnf_core_rules_can_be_extended() {
+    let grammar = parse_abnf(b"ALPHA =/ \"_\"\nname = 1*ALPHA\n").unwrap();
+
+    assert_eq!(grammar.names().collect::<Vec<_>>(), ["ALPHA", "name"]);
+    check(&grammar, "name", &["a_b", "_"], &["a-b", ""]);
+
+    let err = parse_abnf(b"ALPHA =/ \"_\"\nALPHA = \"x\"\n").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::DuplicateRule);
+}
+
+#[test]
+fn ebnf_rules() {
+    let grammar = parse_ebnf(
+        b"(* comment *)\n\
+          digit excluding zero = '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9';\n\
+          digit = '0' | digit excluding zero;\n\
+          natural = digit excluding zero, {digit};\n\
+          integer = '0' | ['-'], natural.\n\
+          pair = 2 * digit, \"!\";\n\
+          letter = 'a' | 'b' | 'c';\n\
+          not b = letter - 'b';\n",
+    )
+    .unwrap();
+
+    check(&grammar, "integer", &["0", "7", "-120"], &["-0", "01", ""]);
+    check(&grammar, "pair", &["00!", "19!"], &["1!", "123!"]);
+    check(&grammar, "not b", &["a", "c"], &["b"]);
+    check(&grammar, "digit excluding zero", &["9"], &["0"]);
+}
+
+#[test]
+fn grammar_errors() {
+    let bump = Bump::new();
+    let expand = |grammar: &Grammar, name| grammar.expand(name, &bump).unwrap().unwrap_err();
+
+    let cases: &[(&[u8], ParseErrorKind)] = &[
+        (b"a = \"x", ParseErrorKind::UnclosedString),
+        (b"a = (\"x\"", ParseErrorKind::UnclosedGroup),
+        (b"a = %x5A-41", ParseErrorKind::InvalidClassRange),
+        (b"a = %x110000", ParseErrorKind::InvalidCodePoint),
+        (
+            b"a = 3*2\"x\"",
+            ParseErrorKind::InvalidRepeatBounds(RepeatKind::Bounded(3, 2)),
+        ),
+        (b"a = \"x\"\na = \"y\"", ParseErrorKind::DuplicateRule),
+        (b"a =/ \"x\"", ParseErrorKind::UndefinedRule),
+    ];
+
+    for &(grammar, kind) in cases {
+        assert_eq!(parse_abnf(grammar).unwrap_err().kind, kind, "{grammar:?}");
+    }
+
+    assert_eq!(
+        parse_ebnf(b"a = 'x").unwrap_err().kind,
+        ParseErrorKind::UnclosedString
+    );
+    assert_eq!(
+        parse_ebnf(b"(* a = 'x';").unwrap_err().kind,
+        ParseErrorKind::UnclosedComment
+    );
+
+    let grammar = parse_abnf(b"a = b\nb = \"x\" a\nc = <prose>\nd = e\n").unwrap();
+    assert_eq!(expand(&grammar, "a").kind, ParseErrorKind::RecursiveRule);
+    assert_eq!(expand(&grammar, "c").kind, ParseErrorKind::UnsupportedProse);
+    assert_eq!(expand(&grammar, "d").kind, ParseErrorKind::UndefinedRule);
+
+    let grammar = parse_ebnf(b"a = ('x', 'y') - 'x';").unwrap();
+    assert_eq!(
+        expand(&grammar, "a").kind,
+        ParseErrorKind::UnsupportedException
+    );
+}
+
+#[test]
+fn repeat_bounds_are_limited() {
+    let bump = Bump::new();
+    let limit = Limits::DEFAULT.repeat;
+
+    let grammar = parse_abnf(b"a = 1001\"x\"\nb = 2*1001\"x\"\nc = 1000\"x\"\n").unwrap();
+
+    for name in ["a", "b"] {
+        let err = grammar.expand(name, &bump).unwrap().unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::RepeatLimitExceeded(limit));
+    }
+
+    assert!(grammar.expand("c", &bump).unwrap().is_ok());
+
+    let grammar = parse_ebnf(b"a = 1001 * 'x';").unwrap();
+    let err = grammar.expand("a", &bump).unwrap().unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::RepeatLimitExceeded(limit));
+    assert_eq!(err.span.start, 4);
+}
+
+#[test]
+fn nesting_is_limited() {
+    let limit = Limits::DEFAULT.nest;
+    let depth = 100_000;
+
+    let abnf = format!("a = {}\"x\"{}", "(".repeat(depth), ")".repeat(depth));
+    let err = parse_abnf(abnf.as_bytes()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(limit));
+
+    let ebnf = format!("a = {}'x'{};", "[".repeat(depth), "]".repeat(depth));
+    let err = parse_ebnf(ebnf.as_bytes()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(limit));
+
+    let nested = format!("a = {}\"x\"{}", "(".repeat(10), ")".repeat(10));
+    assert!(parse_abnf(nested.as_bytes()).is_ok());
+
+    let mut limits = Limits::DEFAULT;
+    limits.nest = 4;
+
+    let err = parse_abnf_with(nested.as_bytes(), limits).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(4));
+    assert_eq!((err.span.start, err.span.end), (8, 9));
+
+    let ebnf = format!("a = {}'x'{};", "[".repeat(10), "]".repeat(10));
+    let err = parse_ebnf_with(ebnf.as_bytes(), limits).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(4));
+
+    limits.nest = 10;
+    assert!(parse_abnf_with(nested.as_bytes(), limits).is_ok());
+}
+
+#[test]
+fn rule_references_are_limited() {
+    let bump = Bump::new();
+    let rules: String = (0..1000).map(|i| format!("r{i} = r{}\n", i + 1)).collect();
+    let grammar = parse_abnf(format!("{rules}r1000 = \"x\"\n").as_bytes()).unwrap();
+
+    let err = grammar.expand("r0", &bump).unwrap().unwrap_err();
+    assert_eq!(
+        err.kind,
+        ParseErrorKind::NestLimitExceeded(Limits::DEFAULT.nest)
+    );
+
+    let mut limits = Limits::DEFAULT;
+    limits.nest = 200;
+    assert!(grammar.expand("r850", &bump).unwrap().is_err());
+    assert!(grammar.expand_with("r850", limits, &bump).unwrap().is_ok());
+
+    limits.nest = 10;
+    let err = grammar
+        .expand_with("r989", limits, &bump)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(10));
+    assert!(grammar.expand_with("r990", limits, &bump).unwrap().is_ok());
+
+    let grammar = parse_abnf(b"a = 1001\"x\"\n").unwrap();
+    limits.repeat = 2000;
+    assert!(grammar.expand_with("a", limits, &bump).unwrap().is_ok());
+}
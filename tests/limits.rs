@@ -0,0 +1,98 @@
+use bumpalo::Bump;
+use regram::{
+    ast::{Ast, Group},
+    parse::{parse, parse_with, Limits, ParseErrorKind, Syntax},
+};
+
+#[test]
+fn nesting_is_limited() {
+    let bump = Bump::new();
+    let mut syntax = Syntax::DEFAULT;
+    syntax.limits.nest = 3;
+
+    assert!(parse_with(b"(((a)))", syntax, &bump).is_ok());
+    assert!(parse_with(b"[[[a]]]", syntax, &bump).is_ok());
+
+    let err = parse_with(b"((((a))))", syntax, &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(3));
+    assert_eq!(err.span.start, 3);
+
+    let err = parse_with(b"[[[[a]]]]", syntax, &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NestLimitExceeded(3));
+}
+
+#[test]
+fn deep_patterns_do_not_overflow_the_stack() {
+    let bump = Bump::new();
+    let depth = 1_000_000;
+    let pattern = format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+
+    let err = parse(pattern.as_bytes(), &bump).unwrap_err();
+    assert_eq!(
+        err.kind,
+        ParseErrorKind::NestLimitExceeded(Limits::DEFAULT.nest)
+    );
+
+    // Trees deeper than the parser allows can still be copied and normalized.
+    let mut ast = parse(b"a", &bump).unwrap();
+
+    for _ in 0..depth {
+        ast = Ast::Group(Group {
+            flags: None,
+            capture: None,
+            atomic: false,
+            child: bump.alloc(ast),
+        });
+    }
+
+    let copy_bump = Bump::new();
+    let mut copy = ast.clone_into(&copy_bump);
+    copy.normalize();
+
+    let mut node = &copy;
+    let mut groups = 0;
+
+    while let Ast::Group(group) = node {
+        node = group.child;
+        groups += 1;
+    }
+
+    assert_eq!(groups, depth);
+    assert!(matches!(node, Ast::Lit(lit) if lit.as_slice() == b"a"));
+}
+
+#[test]
+fn nodes_and_arena_are_limited() {
+    let bump = Bump::new();
+    let pattern = "a|".repeat(1000);
+
+    let mut syntax = Syntax::DEFAULT;
+    syntax.limits.nodes = 100;
+    let err = parse_with(pattern.as_bytes(), syntax, &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::NodeLimitExceeded(100));
+
+    let mut syntax = Syntax::DEFAULT;
+    syntax.limits.arena_bytes = 1024;
+    let err = parse_with(pattern.as_bytes(), syntax, &Bump::new()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::ArenaLimitExceeded(1024));
+
+    assert!(parse(pattern.as_bytes(), &bump).is_ok());
+}
+
+#[test]
+fn repeats_are_limited() {
+    let bump = Bump::new();
+    let mut syntax = Syntax::DEFAULT;
+    syntax.limits.repeat = 10;
+
+    assert!(parse_with(b"a{10}b{2,10}", syntax, &bump).is_ok());
+
+    for pattern in [&b"a{11}"[..], b"a{2,11}", b"a{11,}"] {
+        let err = parse_with(pattern, syntax, &bump).unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::RepeatLimitExceeded(10),
+            "{pattern:?}"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use bumpalo::Bump;
+use regram::{
+    ast::{Ast, Capture, Greediness, Group, Repeat, RepeatKind},
+    exec::Backtracker,
+    parse::{parse, parse_spanned, ParseErrorKind},
+};
+
+/// Check whether `pattern` matches all of `haystack`.
+fn full_match(pattern: &str, haystack: &str) -> bool {
+    let bump = Bump::new();
+    let ast = parse(pattern.as_bytes(), &bump).unwrap();
+
+    Backtracker::new(&ast)
+        .unwrap()
+        .is_full_match(haystack.as_bytes())
+}
+
+#[test]
+fn flags_end_with_lookaround() {
+    assert!(!full_match("(?=(?i))a", "A"));
+    assert!(!full_match("(?!(?i)b)a", "A"));
+    assert!(!full_match("(?<=(?i)a)b|ab", "aB"));
+    assert!(full_match("(?=(?i)A)a", "a"));
+}
+
+#[test]
+fn flags_end_with_atomic_group() {
+    assert!(!full_match("(?>(?i)a)b", "aB"));
+    assert!(full_match("(?>(?i)a)b", "Ab"));
+}
+
+#[test]
+fn backrefs_to_open_groups_are_rejected() {
+    let bump = Bump::new();
+
+    for pattern in [&b"(a\\1)"[..], b"(a(b\\1))", b"(?<n>a\\k<n>)", b"\\1(a)"] {
+        let err = parse(pattern, &bump).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UndefinedGroup, "{pattern:?}");
+    }
+
+    assert!(parse(b"(a)(b\\1)", &bump).is_ok());
+    assert!(parse(b"((a)\\2)", &bump).is_ok());
+}
+
+#[test]
+fn repeated_zero_times_keeps_captures() {
+    let bump = Bump::new();
+    let (ast, mut spans) = parse_spanned(b"(a){0}b\\1", &bump).unwrap();
+    ast.normalize_spanned(&mut spans);
+
+    assert_eq!(ast.captures().len(), 1);
+
+    let backtracker = Backtracker::new(ast).unwrap();
+    assert!(!backtracker.is_full_match(b"b"));
+    assert!(!backtracker.is_full_match(b"ba"));
+}
+
+#[test]
+fn failing_nodes_keep_captures() {
+    let bump = Bump::new();
+
+    for pattern in ["(a)[^\\x00-\\xff]\\1", "(?:x|(a)(?!))\\1"] {
+        let (ast, mut spans) = parse_spanned(pattern.as_bytes(), &bump).unwrap();
+        ast.normalize_spanned(&mut spans);
+
+        assert_eq!(ast.captures().len(), 1, "{pattern:?}");
+
+        let backtracker = Backtracker::new(ast).unwrap();
+        assert!(!backtracker.is_match(b"aa"), "{pattern:?}");
+    }
+
+    let (ast, mut spans) = parse_spanned(b"x|(a)(?!)", &bump).unwrap();
+    ast.normalize_spanned(&mut spans);
+    assert_eq!(ast.captures().len(), 1);
+
+    let backtracker = Backtracker::new(ast).unwrap();
+    assert_eq!(backtracker.captures(b"x"), Some(vec![Some(0..1), None]));
+}
+
+#[test]
+fn case_insensitive_backrefs() {
+    assert!(full_match("(?i)(a)\\1", "aA"));
+    assert!(full_match("(?i)(a)(?-i)\\1", "aa"));
+    assert!(!full_match("(?i)(a)(?-i)\\1", "aA"));
+    assert!(!full_match("(?i)(é)\\1", "éÉ"));
+    assert!(full_match("(?iu)(é)\\1", "éÉ"));
+    assert!(full_match("(?iu)(k)\\1", "k\u{212A}"));
+    assert!(!full_match("(?i)(a)\\1", "ab"));
+}
+
+#[test]
+fn case_insensitive_matching_is_symmetric() {
+    for (a, b) in [("I", "i"), ("K", "\u{212A}"), ("Σ", "ς"), ("ß", "ẞ")] {
+        assert!(full_match(&format!("(?iu){a}"), b), "{a:?} {b:?}");
+        assert!(full_match(&format!("(?iu){b}"), a), "{b:?} {a:?}");
+    }
+
+    for (a, b) in [("I", "ı"), ("i", "İ"), ("ı", "i"), ("İ", "I")] {
+        assert!(!full_match(&format!("(?iu){a}"), b), "{a:?} {b:?}");
+        assert!(!full_match(&format!("(?iu)[{a}]"), b), "{a:?} {b:?}");
+    }
+}
+
+#[test]
+fn case_insensitive_non_ascii_needs_unicode() {
+    assert!(!full_match("(?i)é", "É"));
+    assert!(!full_match("(?i)É", "é"));
+    assert!(!full_match("(?i)\u{212A}", "k"));
+    assert!(full_match("(?i)é", "é"));
+    assert!(full_match("(?iu)é", "É"));
+    assert!(full_match("(?i)k", "K"));
+    assert!(!full_match("(?i)k", "\u{212A}"));
+}
+
+#[test]
+fn case_insensitive_properties() {
+    assert!(full_match("(?i)\\p{Lu}", "a"));
+    assert!(full_match("(?i)[\\p{Lu}]", "a"));
+    assert!(!full_match("(?i)\\P{Lu}", "a"));
+    assert!(!full_match("(?i)[\\P{Lu}]", "A"));
+    assert!(full_match("(?i)\\P{Lu}", "1"));
+    assert!(!full_match("\\p{Lu}", "a"));
+}
+
+#[test]
+fn accepted_shapes() {
+    let bump = Bump::new();
+    let parse = |pattern: &str| parse(pattern.as_bytes(), &bump).unwrap();
+
+    assert!(parse("").is_empty());
+    assert!(matches!(parse("abc"), Ast::Lit(lit) if lit.as_slice() == b"abc"));
+    assert!(matches!(parse("a|b|c"), Ast::Alt(alt) if alt.children.len().get() == 3));
+    assert!(matches!(parse("ab*"), Ast::Concat(concat) if concat.children.len().get() == 2));
+    assert!(matches!(parse("[a-c]"), Ast::Class(class) if class.len().get() == 1));
+    assert!(matches!(
+        parse("(?:a)"),
+        Ast::Group(Group { capture: None, .. })
+    ));
+    assert!(matches!(
+        parse("(?<n>a)"),
+        Ast::Group(Group {
+            capture: Some(Capture {
+                index: 1,
+                name: Some("n")
+            }),
+            ..
+        })
+    ));
+    assert!(matches!(
+        parse("a{2,5}?"),
+        Ast::Repeat(Repeat {
+            kind: RepeatKind::Bounded(2, 5),
+            greediness: Greediness::Lazy,
+            ..
+        })
+    ));
+    assert!(matches!(parse("(?i)"), Ast::Flags(_)));
+}
+
+#[test]
+fn rejected_kinds() {
+    let cases: &[(&str, ParseErrorKind)] = &[
+        ("(a", ParseErrorKind::UnclosedGroup),
+        ("a)", ParseErrorKind::UnopenedGroup),
+        ("*a", ParseErrorKind::MissingRepeatOperand),
+        ("a**", ParseErrorKind::MissingRepeatOperand),
+        ("[a", ParseErrorKind::UnclosedClass),
+        ("a{2", ParseErrorKind::UnclosedRepeat),
+        ("\\", ParseErrorKind::DanglingEscape),
+        ("\\q", ParseErrorKind::UnrecognizedEscape),
+        ("(?z)", ParseErrorKind::UnrecognizedFlag),
+        ("(?)", ParseErrorKind::EmptyFlags),
+        ("[[:foo:]]", ParseErrorKind::UnrecognizedClass),
+        ("[z-a]", ParseErrorKind::InvalidClassRange),
+        ("(?P<1>a)", ParseErrorKind::InvalidGroupName),
+        ("(?<n>a)(?<n>b)", ParseErrorKind::DuplicateGroupName),
+        ("\\k<x>", ParseErrorKind::UndefinedGroup),
+        ("\\x{100}", ParseErrorKind::EscapeOutOfRange),
+    ];
+
+    let bump = Bump::new();
+
+    for &(pattern, kind) in cases {
+        let err = parse(pattern.as_bytes(), &bump).unwrap_err();
+        assert_eq!(err.kind, kind, "{pattern:?}");
+    }
+}
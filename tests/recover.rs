@@ -0,0 +1,48 @@
+use bumpalo::Bump;
+use regram::{
+    ast::Ast,
+    parse::{parse_recovering, ParseErrorKind, Syntax},
+};
+
+#[test]
+fn recovers_at_alternations_and_groups() {
+    let bump = Bump::new();
+    let cases: &[(&str, &[(ParseErrorKind, usize)])] = &[
+        (
+            r"a|b)c|(e\q)f|\",
+            &[
+                (ParseErrorKind::UnopenedGroup, 3),
+                (ParseErrorKind::UnrecognizedEscape, 8),
+                (ParseErrorKind::DanglingEscape, 13),
+            ],
+        ),
+        ("x[d|y", &[(ParseErrorKind::UnclosedClass, 1)]),
+        ("(a|[b)|c", &[(ParseErrorKind::UnclosedClass, 3)]),
+    ];
+
+    for &(pattern, expected) in cases {
+        let (ast, errors) = parse_recovering(pattern.as_bytes(), Syntax::DEFAULT, &bump);
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|err| (err.kind, err.span.start))
+            .collect();
+
+        assert_eq!(errors, expected, "{pattern:?}");
+        assert!(!matches!(ast, Ast::Fail), "{pattern:?}");
+    }
+
+    let (_, errors) = parse_recovering(b"a|(b)", Syntax::DEFAULT, &bump);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn recovery_stops_at_limits() {
+    let bump = Bump::new();
+    let mut syntax = Syntax::DEFAULT;
+    syntax.limits.nodes = 10;
+
+    let (ast, errors) = parse_recovering("a|".repeat(100).as_bytes(), syntax, &bump);
+    assert!(matches!(ast, Ast::Fail));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, ParseErrorKind::NodeLimitExceeded(10));
+}
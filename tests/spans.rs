@@ -0,0 +1,101 @@
+use bumpalo::Bump;
+use regram::{
+    ast::{Ast, SpanMap},
+    parse::parse_spanned,
+};
+
+/// Get every node of `ast`, in preorder.
+fn nodes<'t, 'a>(ast: &'t Ast<'a>) -> Vec<&'t Ast<'a>> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![ast];
+
+    while let Some(node) = stack.pop() {
+        nodes.push(node);
+        stack.extend(node.children().into_iter().flat_map(|c| c.iter()).rev());
+    }
+
+    nodes
+}
+
+/// Check that every node of `ast` has a span within `pattern`, and that `spans` has no
+/// others.
+fn assert_covered(ast: &Ast<'_>, spans: &SpanMap, pattern: &[u8]) {
+    let nodes = nodes(ast);
+
+    for node in &nodes {
+        let span = spans
+            .get(node)
+            .unwrap_or_else(|| panic!("{node:?} has no span"));
+        assert!(span.start <= span.end && span.end <= pattern.len());
+    }
+
+    assert_eq!(spans.len(), nodes.len(), "stale spans in {spans:?}");
+}
+
+#[test]
+fn every_node_has_a_span() {
+    let bump = Bump::new();
+
+    for pattern in [
+        &b"a|bc*"[..],
+        b"(a)(?:b|c)+d",
+        b"x[a-z&&[^q]]{2,3}?",
+        b"(?i)k\\d",
+    ] {
+        let (ast, spans) = parse_spanned(pattern, &bump).unwrap();
+        assert_covered(ast, &spans, pattern);
+    }
+}
+
+#[test]
+fn spans_of_parts() {
+    let bump = Bump::new();
+    let (ast, spans) = parse_spanned(b"ab|c*", &bump).unwrap();
+
+    let Ast::Alt(alt) = &*ast else {
+        panic!("{ast:?} is not an alternation");
+    };
+    let span = |node: &Ast<'_>| {
+        let span = spans.get(node).unwrap();
+        (span.start, span.end)
+    };
+
+    assert_eq!(span(ast), (0, 5));
+    assert_eq!(span(&alt.children[0]), (0, 2));
+    assert_eq!(span(&alt.children[1]), (3, 5));
+}
+
+#[test]
+fn spans_survive_normalize() {
+    let bump = Bump::new();
+
+    for pattern in [
+        &b"(?:a){1}|b{0}c"[..],
+        b"(?:(?:a|b)|c)d",
+        b"[ab]{1}[\\]c]",
+        b"(?:x(?:y(?:z)))",
+        b"a{0}(b){0}",
+        b"(?=a)(?:[^\\x00-\\xFF])*",
+    ] {
+        let (ast, mut spans) = parse_spanned(pattern, &bump).unwrap();
+        ast.normalize_spanned(&mut spans);
+
+        assert_covered(ast, &spans, pattern);
+    }
+}
+
+#[test]
+fn remap_follows_a_copy() {
+    let bump = Bump::new();
+    let pattern = b"(a|b)+c";
+    let (ast, spans) = parse_spanned(pattern, &bump).unwrap();
+
+    let copy = ast.clone_into(&bump);
+    let remapped = spans.remap(ast, &copy);
+
+    assert_covered(&copy, &remapped, pattern);
+
+    for (from, to) in nodes(ast).into_iter().zip(nodes(&copy)) {
+        assert_eq!(spans.get(from), remapped.get(to));
+    }
+}
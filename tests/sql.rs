@@ -0,0 +1,68 @@
+use bumpalo::Bump;
+use regram::{
+    exec::Backtracker,
+    parse::{parse_like, parse_similar, Limits, ParseErrorKind},
+};
+
+fn like(pattern: &str, escape: Option<u8>, haystack: &str) -> bool {
+    let bump = Bump::new();
+    let ast = parse_like(pattern.as_bytes(), escape, &bump).unwrap();
+
+    Backtracker::new(&ast)
+        .unwrap()
+        .is_full_match(haystack.as_bytes())
+}
+
+fn similar(pattern: &str, haystack: &str) -> bool {
+    let bump = Bump::new();
+    let ast = parse_similar(pattern.as_bytes(), None, &bump).unwrap();
+
+    Backtracker::new(&ast)
+        .unwrap()
+        .is_full_match(haystack.as_bytes())
+}
+
+#[test]
+fn like_patterns() {
+    assert!(like("a%", None, "abc"));
+    assert!(like("a%", None, "a"));
+    assert!(!like("a%", None, "ba"));
+    assert!(like("a_c", None, "abc"));
+    assert!(!like("a_c", None, "ac"));
+    assert!(like("a_c", None, "aéc"));
+    assert!(like("___", None, "日本語"));
+    assert!(!like("a.c", None, "abc"));
+    assert!(like("100!%", Some(b'!'), "100%"));
+    assert!(!like("100!%", Some(b'!'), "1000"));
+    assert!(like("a!!", Some(b'!'), "a!"));
+}
+
+#[test]
+fn like_errors() {
+    let bump = Bump::new();
+
+    let err = parse_like(b"ab!c", Some(b'!'), &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidEscape);
+    assert_eq!((err.span.start, err.span.end), (2, 4));
+
+    let err = parse_like(b"ab!", Some(b'!'), &bump).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::DanglingEscape);
+
+    let pattern = "%".repeat(Limits::DEFAULT.nodes + 1);
+    let err = parse_like(pattern.as_bytes(), None, &bump).unwrap_err();
+    assert_eq!(
+        err.kind,
+        ParseErrorKind::NodeLimitExceeded(Limits::DEFAULT.nodes)
+    );
+}
+
+#[test]
+fn similar_patterns() {
+    assert!(similar("(a|b)*c", "abac"));
+    assert!(!similar("(a|b)*c", "abacd"));
+    assert!(similar("a_c", "aéc"));
+    assert!(similar("a.c", "a.c"));
+    assert!(!similar("a.c", "abc"));
+    assert!(similar("[a-c]{2}%", "bcxyz"));
+    assert!(similar("a+", "aaa"));
+}
@@ -0,0 +1,114 @@
+use regram::{
+    ast::CharRange,
+    unicode::{self, Utf8Sequences},
+};
+
+/// Scalar values at and around the boundaries of UTF-8 encoding lengths and surrogates,
+/// along with a sample of the rest.
+fn samples() -> impl Iterator<Item = char> {
+    let boundaries = [
+        0, 0x7F, 0x80, 0x7FF, 0x800, 0xFFF, 0x1000, 0xD7FF, 0xE000, 0xFFFF, 0x10000, 0x3FFFF,
+        0x40000, 0xFFFFF, 0x100000, 0x10FFFF,
+    ];
+
+    boundaries
+        .into_iter()
+        .flat_map(|b: u32| b.saturating_sub(2)..=b + 2)
+        .chain((0..0x110000).step_by(97))
+        .filter_map(char::from_u32)
+}
+
+fn check(ranges: &[CharRange]) {
+    let sequences: Vec<_> = Utf8Sequences::new(ranges).collect();
+
+    for c in samples() {
+        let mut buf = [0; 4];
+        let bytes = c.encode_utf8(&mut buf).as_bytes();
+        let expected = ranges.iter().any(|range| range.to_inclusive().contains(&c));
+        let matched = sequences.iter().filter(|seq| seq.matches(bytes)).count();
+
+        assert_eq!(matched, usize::from(expected), "{c:?} in {ranges:?}");
+    }
+
+    // Encoded surrogates are not UTF-8.
+    for bytes in [[0xED, 0xA0, 0x80], [0xED, 0xBF, 0xBF]] {
+        assert!(!sequences.iter().any(|seq| seq.matches(&bytes)));
+    }
+
+    assert!(sequences.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn sequences_match_exactly_their_ranges() {
+    check(&[CharRange::new('\0', char::MAX)]);
+    check(&[CharRange::new('a', 'z')]);
+    check(&[CharRange::new('\u{7F}', '\u{80}')]);
+    check(&[CharRange::new('\u{7FF}', '\u{10000}')]);
+    check(&[CharRange::new('\u{D000}', '\u{E0FF}')]);
+    check(&[
+        CharRange::new('0', '9'),
+        CharRange::new('é', 'é'),
+        CharRange::new('\u{3040}', '\u{30FF}'),
+        CharRange::new('\u{1F600}', '\u{1F64F}'),
+    ]);
+
+    let greek: Vec<_> = unicode::property("Greek")
+        .unwrap()
+        .iter()
+        .map(|&range| CharRange::from(range))
+        .collect();
+    check(&greek);
+}
+
+#[test]
+fn sequences_of_single_values() {
+    let ranges = [CharRange::new('€', '€')];
+    let sequences: Vec<_> = Utf8Sequences::new(&ranges).collect();
+
+    assert_eq!(sequences.len(), 1);
+    assert!(sequences[0].matches("€".as_bytes()));
+    assert!(Utf8Sequences::new(&[]).next().is_none());
+}
+
+#[test]
+fn properties() {
+    let contains = |name: &str, c: char| {
+        unicode::property(name)
+            .unwrap()
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&c))
+    };
+
+    assert!(contains("Lu", 'A'));
+    assert!(!contains("Lu", 'a'));
+    assert!(contains("General_Category=Lowercase_Letter", 'a'));
+    assert!(contains("sc=Greek", 'λ'));
+    assert!(contains("isGreek", 'λ'));
+    assert!(contains("White_Space", '\u{3000}'));
+    assert!(unicode::property("NotAProperty").is_none());
+
+    assert!(unicode::is_word_char('é'));
+    assert!(!unicode::is_word_char('-'));
+    assert_eq!(unicode::simple_case_fold('k'), &['K', '\u{212A}']);
+}
+
+#[test]
+fn simple_case_folds_are_symmetric() {
+    for c in (0..0x11_0000).filter_map(char::from_u32) {
+        let mut class = unicode::simple_case_fold(c).to_vec();
+        class.push(c);
+        class.sort_unstable();
+
+        for &other in unicode::simple_case_fold(c) {
+            let mut other_class = unicode::simple_case_fold(other).to_vec();
+            other_class.push(other);
+            other_class.sort_unstable();
+
+            assert_eq!(class, other_class, "{c:?} and {other:?}");
+        }
+    }
+
+    assert_eq!(unicode::simple_case_fold('I'), &['i']);
+    assert_eq!(unicode::simple_case_fold('ı'), &[] as &[char]);
+    assert_eq!(unicode::simple_case_fold('İ'), &[] as &[char]);
+}